@@ -19,7 +19,16 @@ pub mod derive_attr {
         pub not_empty: Option<()>,
         pub list_sort_position: Option<syn::LitStr>,
         pub list_hide_column: Option<()>,
-        pub list_regex_mask: Option<syn::LitStr>
+        pub list_regex_mask: Option<syn::LitStr>,
+        pub label: Option<syn::LitStr>,
+        pub help_text: Option<syn::LitStr>,
+        pub placeholder: Option<syn::LitStr>,
+        pub readonly_after_create: Option<()>,
+        pub show_only: Option<()>,
+        pub form_hidden: Option<()>,
+        pub version: Option<()>,
+        pub filter: Option<syn::LitStr>,
+        pub renderer: Option<syn::LitStr>
         //pub inner_type: Option<syn::Type>,
 
         // Anything that implements `syn::parse::Parse` is supported.
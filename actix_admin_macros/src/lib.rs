@@ -52,11 +52,65 @@ pub fn derive_actix_admin_view_model(input: proc_macro::TokenStream) -> proc_mac
     let fields = get_fields_for_tokenstream(input);
 
     let name_primary_field_str = get_primary_key_field_name(&fields);
+    let primary_key_column = get_primary_key_column(&fields);
+    let version_field = get_version_field(&fields);
+    let version_field_name = match version_field {
+        Some(model_field) => {
+            let ident_name = model_field.ident.to_string();
+            quote! { Some(#ident_name.to_string()) }
+        },
+        None => quote! { None }
+    };
+    let set_initial_version = match version_field {
+        Some(model_field) => {
+            let ident = model_field.ident.to_owned();
+            let ty = model_field.ty.to_owned();
+            quote! { new_model.#ident = Set(<#ty as ActixAdminVersionTrait>::initial_version()); }
+        },
+        None => quote! {}
+    };
+    // with a version field the update is conditional on the version which was loaded with the form
+    let update_entity = match version_field {
+        Some(model_field) => {
+            let ident_name = model_field.ident.to_string();
+            let ident = model_field.ident.to_owned();
+            let ty = model_field.ty.to_owned();
+            let version_column = get_column_for_field(model_field);
+            quote! {
+                let current_version = entity.#ident.clone().unwrap();
+                let loaded_version = model.values.get(#ident_name).and_then(|value| <#ty as ActixAdminVersionTrait>::parse_version(value));
+                let next_version = current_version.next_version();
+                entity.#ident = Set(next_version.clone());
+
+                let rows_affected = match loaded_version == Some(current_version.clone()) {
+                    true => Entity::update_many()
+                        .set(entity.clone())
+                        .filter(#primary_key_column.eq(id))
+                        .filter(#version_column.eq(current_version))
+                        .exec(&txn)
+                        .await?
+                        .rows_affected,
+                    false => 0
+                };
+                if rows_affected == 0 {
+                    let current = Entity::find_by_id(id).one(&txn).await?.ok_or(ActixAdminError::EntityDoesNotExistError)?;
+                    entity.#ident = Set(current.#ident.clone());
+                    let submitted = ActixAdminModel::from(Model::try_from(entity)?);
+                    return Err(ActixAdminError::ConflictError(ActixAdminConflict::new(&ActixAdminModel::from(current), &submitted, Entity::get_fields(), #ident_name)));
+                }
+                model.values.insert(#ident_name.to_string(), next_version.to_string());
+            }
+        },
+        None => quote! {
+            let entity: Model = entity.update(&txn).await?;
+        }
+    };
     let fields_for_edit_model = get_fields_for_edit_model(&fields);
-    let fields_searchable = get_actix_admin_fields_searchable(&fields);
-    let has_searchable_fields = fields_searchable.len() > 0;
+    let has_searchable_fields = fields.iter().any(|model_field| model_field.searchable);
 
     let select_lists = get_select_lists(&fields);
+    let fields_for_filter = get_fields_for_filter(&fields);
+    let has_filters = fields_for_filter.len() > 0;
 
     let expanded = quote! {
         impl From<Entity> for ActixAdminViewModel {
@@ -67,19 +121,32 @@ pub fn derive_actix_admin_view_model(input: proc_macro::TokenStream) -> proc_mac
                     fields: Entity::get_fields(),
                     show_search: #has_searchable_fields,
                     user_can_access: None,
-                    default_show_aside: Entity::get_filter().len() > 0
+                    default_show_aside: !Entity::get_filter().is_empty() || !Entity::get_range_filter().is_empty() || #has_filters,
+                    pagination_type: ActixAdminPaginationType::Offset,
+                    count_type: ActixAdminCountType::Exact,
+                    version_field: #version_field_name
                 }
             }
         }
 
         #[actix_admin::prelude::async_trait(?Send)]
         impl ActixAdminViewModelTrait for Entity {
-            async fn list(db: &DatabaseConnection, page: u64, entities_per_page: u64, viewmodel_filter: Vec<ActixAdminViewModelFilter>, search: &str, sort_by: &str, sort_order: &SortOrder) -> Result<(u64, Vec<ActixAdminModel>), ActixAdminError> {
+            async fn list(db: &DatabaseConnection, page: u64, entities_per_page: u64, cursor: Option<ActixAdminCursor>, viewmodel_filter: Vec<ActixAdminViewModelFilter>, search: &str, sort_by: &str, sort_order: &SortOrder) -> Result<(Vec<ActixAdminModel>, bool), ActixAdminError> {
                 let filter_values: HashMap<String, Option<String>> = viewmodel_filter.iter().map(|f| (f.name.to_string(), f.value.clone())).collect();
-                let entities = Entity::list_model(db, page, entities_per_page, filter_values, search, sort_by, sort_order).await;
+                let entities = Entity::list_model(db, page, entities_per_page, cursor, filter_values, search, sort_by, sort_order).await;
                 entities
             }
 
+            async fn count(db: &DatabaseConnection, viewmodel_filter: Vec<ActixAdminViewModelFilter>, search: &str) -> Result<u64, ActixAdminError> {
+                let filter_values: HashMap<String, Option<String>> = viewmodel_filter.iter().map(|f| (f.name.to_string(), f.value.clone())).collect();
+                Entity::count_model(db, filter_values, search).await
+            }
+
+            fn validate_search(search: &str) -> Vec<String> {
+                let (_, invalid_terms) = Entity::get_actix_admin_search_condition(search);
+                invalid_terms
+            }
+
             fn validate_entity(model: &mut ActixAdminModel) {
                 Entity::validate_model(model);
 
@@ -87,19 +154,36 @@ pub fn derive_actix_admin_view_model(input: proc_macro::TokenStream) -> proc_mac
                     let active_model = ActiveModel::from(model.clone());
                     let custom_errors = Entity::validate(&active_model);
                     model.custom_errors = custom_errors;
+                    model.form_errors = Entity::validate_form(&active_model);
+                }
+            }
+
+            async fn validate_entity_async(db: &DatabaseConnection, session: &Session, mode: &ActixAdminValidationMode, model: &mut ActixAdminModel) -> Result<(), ActixAdminError> {
+                if !model.has_errors() {
+                    let active_model = ActiveModel::from(model.clone());
+                    let validation_errors = Entity::validate_async(&active_model, db, session, mode).await?;
+                    model.custom_errors.extend(validation_errors.field_errors);
+                    model.form_errors.extend(validation_errors.form_errors);
                 }
+                Ok(())
             }
 
             async fn create_entity(db: &DatabaseConnection, mut model: ActixAdminModel) -> Result<ActixAdminModel, ActixAdminError> {
-                let new_model = ActiveModel::from(model.clone());
-                let insert_operation = Entity::insert(new_model).exec(db).await?;
+                let txn = sea_orm::TransactionTrait::begin(db).await?;
+                Entity::before_create(&txn, &mut model).await?;
+                let mut new_model = ActiveModel::from(model.clone());
+                #set_initial_version
+                let insert_operation = Entity::insert(new_model).exec(&txn).await?;
                 model.primary_key = Some(insert_operation.last_insert_id.to_string());
+                Entity::after_create(&txn, &model).await?;
+                txn.commit().await?;
 
                 Ok(model)
             }
 
             async fn get_viewmodel_filter(db: &DatabaseConnection) -> HashMap<String, ActixAdminViewModelFilter> {
                 let mut hashmap: HashMap<String, ActixAdminViewModelFilter> = HashMap::new();
+                #(#fields_for_filter)*
 
                 for filter in Entity::get_filter() {
                     hashmap.insert(
@@ -107,32 +191,41 @@ pub fn derive_actix_admin_view_model(input: proc_macro::TokenStream) -> proc_mac
                         ActixAdminViewModelFilter {
                             name: filter.name.to_string(),
                             value: None,
+                            value_to: None,
                             values: Entity::get_filter_values(&filter, db).await,
                             filter_type: Some(filter.filter_type)
                         }
                     );
                 };
 
+                for filter in Entity::get_range_filter() {
+                    hashmap.insert(filter.name.to_string(), filter.into());
+                };
+
                 hashmap
             }
 
             async fn get_entity(db: &DatabaseConnection, id: i32) -> Result<ActixAdminModel, ActixAdminError> {
                 // TODO: separate primary key from other keys
-                let entity = Entity::find_by_id(id).one(db).await?;
-                match entity {
-                    Some(e) => Ok(ActixAdminModel::from(e)),
+                let entities = find_with_computed_columns(db, Entity::find_by_id(id), &Entity::get_computed_columns()).await?;
+                match entities.into_iter().next() {
+                    Some(model) => Ok(model),
                     _ => Err(ActixAdminError::EntityDoesNotExistError)
                 }
             }
 
             async fn edit_entity(db: &DatabaseConnection, id: i32, mut model: ActixAdminModel) -> Result<ActixAdminModel, ActixAdminError> {
-                let entity: Option<Model> = Entity::find_by_id(id).one(db).await?;
+                let txn = sea_orm::TransactionTrait::begin(db).await?;
+                let entity: Option<Model> = Entity::find_by_id(id).one(&txn).await?;
 
                 match entity {
                     Some(e) => {
+                        Entity::before_update(&txn, id, &mut model).await?;
                         let mut entity: ActiveModel = e.into();
                         #(#fields_for_edit_model);*;
-                        let entity: Model = entity.update(db).await?;
+                        #update_entity
+                        Entity::after_update(&txn, id, &model).await?;
+                        txn.commit().await?;
                         Ok(model)
                     },
                     _ => Err(ActixAdminError::EntityDoesNotExistError)
@@ -140,14 +233,57 @@ pub fn derive_actix_admin_view_model(input: proc_macro::TokenStream) -> proc_mac
             }
 
             async fn delete_entity(db: &DatabaseConnection, id: i32) -> Result<bool, ActixAdminError> {
-                let result = Entity::delete_by_id(id).exec(db).await;
+                let txn = sea_orm::TransactionTrait::begin(db).await?;
+                Entity::before_delete(&txn, id).await?;
+                let result = Entity::delete_by_id(id).exec(&txn).await;
 
                 match result {
-                    Ok(_) => Ok(true),
+                    Ok(_) => {
+                        Entity::after_delete(&txn, id).await?;
+                        txn.commit().await?;
+                        Ok(true)
+                    },
                     Err(_) => Err(ActixAdminError::DeleteError)
                 }
             }
 
+            async fn delete_entities(db: &DatabaseConnection, ids: &[i32]) -> Result<Vec<ActixAdminModel>, ActixAdminError> {
+                let txn = sea_orm::TransactionTrait::begin(db).await?;
+                let models: Vec<ActixAdminModel> = Entity::find()
+                    .filter(#primary_key_column.is_in(ids.iter().copied()))
+                    .all(&txn)
+                    .await?
+                    .into_iter()
+                    .map(ActixAdminModel::from)
+                    .collect();
+
+                let mut failures: Vec<(i32, ActixAdminError)> = Vec::new();
+                for id in ids {
+                    if !models.iter().any(|model| model.primary_key == Some(id.to_string())) {
+                        failures.push((*id, ActixAdminError::EntityDoesNotExistError));
+                    } else if let Err(err) = Entity::before_delete(&txn, *id).await {
+                        failures.push((*id, err));
+                    }
+                }
+
+                if failures.is_empty() {
+                    Entity::delete_many().filter(#primary_key_column.is_in(ids.iter().copied())).exec(&txn).await?;
+                    for id in ids {
+                        if let Err(err) = Entity::after_delete(&txn, *id).await {
+                            failures.push((*id, err));
+                        }
+                    }
+                }
+
+                // dropping the transaction without commit rolls back the delete
+                if !failures.is_empty() {
+                    return Err(ActixAdminError::DeleteManyError(failures));
+                }
+                txn.commit().await?;
+
+                Ok(models)
+            }
+
             async fn get_select_lists(db: &DatabaseConnection) -> Result<HashMap<String, Vec<(String, String)>>, ActixAdminError> {
                 Ok(hashmap![
                     #(#select_lists),*
@@ -157,6 +293,10 @@ pub fn derive_actix_admin_view_model(input: proc_macro::TokenStream) -> proc_mac
             fn get_entity_name() -> String {
                 Entity.table_name().to_string()
             }
+
+            fn get_table_name() -> String {
+                Entity.table_name().to_string()
+            }
         }
     };
 
@@ -176,6 +316,18 @@ pub fn derive_actix_admin_model(input: proc_macro::TokenStream) -> proc_macro::T
     let field_list_regex_mask = get_fields_as_tokenstream(&fields, |model_field| -> String {
         model_field.list_regex_mask.to_string()
     });
+    let field_labels = get_fields_as_tokenstream(&fields, |model_field| -> String {
+        model_field.label.to_string()
+    });
+    let field_help_texts = get_fields_as_tokenstream(&fields, |model_field| -> String {
+        model_field.help_text.to_string()
+    });
+    let field_placeholders = get_fields_as_tokenstream(&fields, |model_field| -> String {
+        model_field.placeholder.to_string()
+    });
+    let field_renderers = get_fields_as_tokenstream(&fields, |model_field| -> String {
+        model_field.renderer.to_string()
+    });
     let field_select_list = get_fields_as_tokenstream(&fields, |model_field| -> String {
         model_field.select_list.to_string()
     });
@@ -185,6 +337,7 @@ pub fn derive_actix_admin_model(input: proc_macro::TokenStream) -> proc_macro::T
     let fields_for_from_model = get_fields_for_from_model(&fields);
     let field_for_primary_key = get_field_for_primary_key(&fields);
     let fields_for_validate_model = get_fields_for_validate_model(&fields);
+    let fields_for_filter_query = get_fields_for_filter_query(&fields);
     let fields_type_path = get_fields_as_tokenstream(&fields, |model_field| -> String {
         model_field.get_type_path_string()
     });
@@ -197,10 +350,19 @@ pub fn derive_actix_admin_model(input: proc_macro::TokenStream) -> proc_macro::T
         model_field.list_sort_position
     });
     let fields_list_hide_column = get_fields_as_tokenstream(&fields, |model_field| -> bool {
-        model_field.list_hide_column
+        model_field.list_hide_column || model_field.show_only
+    });
+    let fields_readonly_after_create = get_fields_as_tokenstream(&fields, |model_field| -> bool {
+        model_field.readonly_after_create
+    });
+    let fields_form_hidden = get_fields_as_tokenstream(&fields, |model_field| -> bool {
+        model_field.is_excluded_from_form()
     });
     let fields_searchable = get_actix_admin_fields_searchable(&fields);
     let has_searchable_fields = fields_searchable.len() > 0;
+    let primary_key_field_name = get_primary_key_field_name(&fields);
+    let primary_key_column = get_primary_key_column(&fields);
+    let fields_for_search = get_fields_for_search(&fields);
 
     let expanded = quote! {
         actix_admin::prelude::lazy_static! {
@@ -250,11 +412,35 @@ pub fn derive_actix_admin_model(input: proc_macro::TokenStream) -> proc_macro::T
                     #(#field_list_regex_mask),*
                 ];
 
-                for (field_name, html_input_type, select_list, is_option_list, fields_type_path, is_textarea, is_file_upload, list_sort_position, list_hide_column, list_regex_mask) in actix_admin::prelude::izip!(&field_names, &html_input_types, &field_select_lists, is_option_lists, fields_type_paths, fields_textareas, fields_fileupload, list_sort_positions, list_hide_columns, list_regex_masks) {
+                let labels = [
+                    #(#field_labels),*
+                ];
+
+                let help_texts = [
+                    #(#field_help_texts),*
+                ];
+
+                let placeholders = [
+                    #(#field_placeholders),*
+                ];
 
-                    let select_list = select_list.replace('"', "").replace(' ', "").to_string();
-                    let field_name = field_name.replace('"', "").replace(' ', "").to_string();
-                    let html_input_type = html_input_type.replace('"', "").replace(' ', "").to_string();
+                let readonly_after_creates = [
+                    #(#fields_readonly_after_create),*
+                ];
+
+                let form_hiddens = [
+                    #(#fields_form_hidden),*
+                ];
+
+                let renderers = [
+                    #(#field_renderers),*
+                ];
+
+                for (field_name, html_input_type, select_list, is_option_list, fields_type_path, is_textarea, is_file_upload, list_sort_position, list_hide_column, list_regex_mask, label, help_text, placeholder, readonly_after_create, form_hidden, renderer) in actix_admin::prelude::izip!(&field_names, &html_input_types, &field_select_lists, is_option_lists, fields_type_paths, fields_textareas, fields_fileupload, list_sort_positions, list_hide_columns, list_regex_masks, labels, help_texts, placeholders, readonly_after_creates, form_hiddens, renderers) {
+
+                    let select_list = select_list.replace('"', "").replace(char::is_whitespace, "").to_string();
+                    let field_name = field_name.replace('"', "").replace(char::is_whitespace, "").to_string();
+                    let html_input_type = html_input_type.replace('"', "").replace(char::is_whitespace, "").to_string();
                     let mut list_regex_mask_regex = None;
                     if list_regex_mask != "" {
                         list_regex_mask_regex = Some(Regex::new(list_regex_mask).unwrap());
@@ -268,9 +454,19 @@ pub fn derive_actix_admin_model(input: proc_macro::TokenStream) -> proc_macro::T
                         list_sort_position: list_sort_position,
                         field_type: ActixAdminViewModelFieldType::get_field_type(fields_type_path, select_list, is_textarea, is_file_upload),
                         list_hide_column: list_hide_column,
-                        list_regex_mask: list_regex_mask_regex
+                        list_regex_mask: list_regex_mask_regex,
+                        label: (!label.is_empty()).then(|| label.to_string()),
+                        help_text: (!help_text.is_empty()).then(|| help_text.to_string()),
+                        placeholder: (!placeholder.is_empty()).then(|| placeholder.to_string()),
+                        readonly_after_create: readonly_after_create,
+                        form_hidden: form_hidden,
+                        is_computed: false,
+                        is_sortable: true,
+                        renderer: (!renderer.is_empty()).then(|| renderer.to_string()),
+                        number_input: ActixAdminNumberInput::from_type_path(fields_type_path)
                     });
                 }
+                vec.extend(Entity::get_computed_columns().iter().map(ActixAdminViewModelField::from));
                 vec
             };
         }
@@ -284,6 +480,7 @@ pub fn derive_actix_admin_model(input: proc_macro::TokenStream) -> proc_macro::T
                     ],
                     errors: HashMap::new(),
                     custom_errors: HashMap::new(),
+                    form_errors: Vec::new(),
                 }
             }
         }
@@ -299,50 +496,118 @@ pub fn derive_actix_admin_model(input: proc_macro::TokenStream) -> proc_macro::T
             }
         }
 
-        #[actix_admin::prelude::async_trait]
-        impl ActixAdminModelTrait for Entity {
-            async fn list_model(db: &DatabaseConnection, page: u64, posts_per_page: u64, filter_values: HashMap<String, Option<String>>, search: &str, sort_by: &str, sort_order: &SortOrder) -> Result<(u64, Vec<ActixAdminModel>), ActixAdminError> {
-                let sort_column = match sort_by {
-                    #(#fields_match_name_to_columns)*
-                    _ => panic!("Unknown column")
-                };
-
-                let mut query = if sort_order.eq(&SortOrder::Asc) {
-                    Entity::find().order_by_asc(sort_column)
-                } else {
-                    Entity::find().order_by_desc(sort_column)
-                };
+        impl Entity {
+            fn get_actix_admin_search_condition(search: &str) -> (Condition, Vec<String>) {
+                let mut condition = Condition::all();
+                let mut invalid_terms = Vec::new();
+
+                for term in parse_search_terms(search) {
+                    let term_condition = term.and_then(|term| {
+                        let term_condition = match term.field_name.as_deref() {
+                            #(#fields_for_search)*
+                            // terms with a field which can't be searched, e.g. a URL, are searched as text
+                            _ if #has_searchable_fields => {
+                                let term = term.clone().into_plain_text();
+                                Ok(
+                                    Condition::any()
+                                    #(#fields_searchable)*
+                                )
+                            },
+                            _ => Err(format!("Invalid search term {}: use field:value", term.raw))
+                        };
+
+                        match term.is_negated {
+                            true => term_condition.map(|c| c.not()),
+                            false => term_condition
+                        }
+                    });
 
-                if (#has_searchable_fields) {
-                    query = query
-                    .filter(
-                        Condition::any()
-                        #(#fields_searchable)*
-                    )
+                    match term_condition {
+                        Ok(term_condition) => condition = condition.add(term_condition),
+                        Err(invalid_term) => invalid_terms.push(invalid_term)
+                    }
                 }
 
+                (condition, invalid_terms)
+            }
+
+            fn get_actix_admin_filtered_query(filter_values: &HashMap<String, Option<String>>, search: &str) -> sea_orm::Select<Entity> {
+                let (search_condition, _) = Entity::get_actix_admin_search_condition(search);
+                let mut query = Entity::find().filter(search_condition);
+
                 let filters = Entity::get_filter();
+                let range_filters = Entity::get_range_filter();
+                #(#fields_for_filter_query)*
                 for filter in filters {
                     let myfn = filter.filter;
                     let value = filter_values.get(&filter.name).unwrap_or_else(|| &None);
                     query = myfn(query, value.clone());
                 }
+                for filter in range_filters {
+                    let from = filter_values.get(&format!("{}_from", filter.name)).cloned().flatten();
+                    let to = filter_values.get(&format!("{}_to", filter.name)).cloned().flatten();
+                    query = (filter.filter)(query, from, to);
+                }
 
-                let paginator = query.paginate(db, posts_per_page);
-                let num_pages = paginator.num_pages().await?;
+                query
+            }
+        }
 
-                let mut model_entities = Vec::new();
-                if (num_pages == 0) { return Ok((num_pages, model_entities)) };
-                let entities = paginator
-                    .fetch_page(std::cmp::min(num_pages - 1, page - 1))
-                    .await?;
-                for entity in entities {
-                    model_entities.push(
-                        ActixAdminModel::from(entity)
-                    );
+        #[actix_admin::prelude::async_trait]
+        impl ActixAdminModelTrait for Entity {
+            async fn list_model(db: &DatabaseConnection, page: u64, posts_per_page: u64, cursor: Option<ActixAdminCursor>, filter_values: HashMap<String, Option<String>>, search: &str, sort_by: &str, sort_order: &SortOrder) -> Result<(Vec<ActixAdminModel>, bool), ActixAdminError> {
+                let computed_columns = Entity::get_computed_columns();
+                let filtered_query = Entity::get_actix_admin_filtered_query(&filter_values, search);
+
+                // computed columns are sorted by their expression, a cursor is not supported for them
+                let query = match apply_sort_by_computed_column(filtered_query.clone(), &computed_columns, sort_by, #primary_key_column, sort_order) {
+                    Some(query) => query,
+                    None => {
+                        let sort_column = match sort_by {
+                            #(#fields_match_name_to_columns)*
+                            _ => panic!("Unknown column")
+                        };
+
+                        apply_sort_and_cursor(
+                            filtered_query,
+                            sort_column,
+                            #primary_key_column,
+                            sort_by == #primary_key_field_name,
+                            sort_order,
+                            cursor.as_ref()
+                        )
+                    }
+                };
+
+                // a cursor replaces the offset, one additional entity is fetched to determine if there are more
+                let offset = match cursor {
+                    Some(_) => 0,
+                    None => (std::cmp::max(page, 1) - 1) * posts_per_page
+                };
+                let entities = find_with_computed_columns(
+                    db,
+                    query.offset(offset).limit(posts_per_page + 1),
+                    &computed_columns
+                ).await?;
+                let has_more = entities.len() as u64 > posts_per_page;
+
+                let mut model_entities: Vec<ActixAdminModel> = entities
+                    .into_iter()
+                    .take(posts_per_page as usize)
+                    .collect();
+                if cursor.map_or(false, |c| c.is_before()) {
+                    model_entities.reverse();
                 }
 
-                Ok((num_pages, model_entities))
+                Ok((model_entities, has_more))
+            }
+
+            async fn count_model(db: &DatabaseConnection, filter_values: HashMap<String, Option<String>>, search: &str) -> Result<u64, ActixAdminError> {
+                let num_entities = Entity::get_actix_admin_filtered_query(&filter_values, search)
+                    .count(db)
+                    .await?;
+
+                Ok(num_entities)
             }
 
             fn validate_model(model: &mut ActixAdminModel) {
@@ -17,7 +17,18 @@ pub struct ModelField {
     pub not_empty: bool,
     pub list_sort_position: usize,
     pub list_hide_column: bool,
-    pub list_regex_mask: String
+    pub list_regex_mask: String,
+    pub label: String,
+    pub help_text: String,
+    pub placeholder: String,
+    pub readonly_after_create: bool,
+    pub show_only: bool,
+    pub form_hidden: bool,
+    /// Column for optimistic locking, which is carried by a hidden input instead of a form field
+    pub version: bool,
+    /// Kind of the filter declared with `#[actix_admin(filter)]`: `eq`, `contains` or `range`
+    pub filter: Option<String>,
+    pub renderer: String
 }
 
 impl ModelField {
@@ -25,6 +36,20 @@ impl ModelField {
         self.inner_type.is_some()
     }
 
+    /// Fields which are not part of the create and edit forms
+    pub fn is_excluded_from_form(&self) -> bool {
+        self.show_only || self.form_hidden
+    }
+
+    /// Kind of the filter of the field, fields with an enum select list are filtered by default
+    pub fn get_filter_kind(&self) -> Option<&str> {
+        match &self.filter {
+            Some(filter) => Some(filter.as_str()),
+            None if self.is_enum_select_list() => Some("eq"),
+            None => None
+        }
+    }
+
     pub fn is_string(&self) -> bool {
         match &self.ty {
             Type::Path(type_path) if type_path.clone().into_token_stream().to_string() == "String" => {
@@ -34,6 +59,24 @@ impl ModelField {
         }
     }
 
+    /// Fields with a select list of their own type, e.g. an enum, in contrast to a select list of related entities
+    pub fn is_enum_select_list(&self) -> bool {
+        !self.select_list.is_empty() && self.select_list == self.get_type_path_string()
+    }
+
+    /// Fields with a select list which are converted from and to the keys of the options
+    pub fn is_select_list_key(&self) -> bool {
+        !self.select_list.is_empty() && !self.is_string()
+    }
+
+    /// Fields of the sea-orm Json type, which is an alias for serde_json::Value
+    pub fn is_json(&self) -> bool {
+        matches!(
+            self.get_type_path_string().as_str(),
+            "Json" | "JsonValue" | "serde_json :: Value"
+        )
+    }
+
     pub fn get_type_path_string(&self) -> String {
         let type_path_string: String;
         if self.is_option() {
@@ -1,7 +1,8 @@
 use syn::{
-    DeriveInput, Ident
+    Data, DeriveInput, Ident, LitStr, Meta, NestedMeta
 };
 use quote::quote;
+use crate::attributes::derive_attr;
 use crate::model_fields::{ ModelField };
 use proc_macro2::{Span};
 
@@ -32,14 +33,66 @@ pub fn get_select_list_from_enum(input: proc_macro::TokenStream) -> proc_macro::
     let ast: DeriveInput = syn::parse(input).unwrap();
     let (_vis, ty, _generics) = (&ast.vis, &ast.ident, &ast.generics);
 
+    // enums deriving DeriveActiveEnum have the rs_type of their database values in the sea_orm attribute
+    let is_active_enum = ast.attrs.iter().filter(|attr| attr.path.is_ident("sea_orm")).any(|attr| {
+        match attr.parse_meta() {
+            Ok(Meta::List(list)) => list.nested.iter().any(|nested| {
+                matches!(nested, NestedMeta::Meta(Meta::NameValue(name_value)) if name_value.path.is_ident("rs_type"))
+            }),
+            _ => false
+        }
+    });
+
+    let variants = match &ast.data {
+        Data::Enum(data_enum) => data_enum.variants.iter().collect::<Vec<_>>(),
+        _ => panic!("DeriveActixAdminEnumSelectList can only be derived for enums"),
+    };
+    let labels = variants
+        .iter()
+        .map(|variant| {
+            let ident = &variant.ident;
+            let label = derive_attr::ActixAdmin::try_from_attributes(&variant.attrs)
+                .unwrap_or_default()
+                .and_then(|attr| attr.label)
+                .map(|label| LitStr::from(label).value());
+            match label {
+                Some(label) => quote! { #ty::#ident => #label.to_string() },
+                None => quote! { #ty::#ident => key.clone() },
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let select_list_keys = match is_active_enum {
+        true => quote! {
+            impl #ty {
+                /// Returns the variant of the database value
+                pub fn from_select_list_key(key: &str) -> Option<Self> {
+                    let value = key.parse::<<Self as sea_orm::ActiveEnum>::Value>().ok()?;
+                    <Self as sea_orm::ActiveEnum>::try_from_value(&value).ok()
+                }
+
+                /// Returns the database value of the variant
+                pub fn to_select_list_key(&self) -> String {
+                    <Self as sea_orm::ActiveEnum>::to_value(self).to_string()
+                }
+            }
+        },
+        false => quote! {}
+    };
+
     let expanded = quote! {
+        #select_list_keys
+
         #[async_trait]
         impl ActixAdminSelectListTrait for #ty {
             async fn get_key_value(db: &DatabaseConnection) -> Result<Vec<(String, String)>, ActixAdminError> {
                 let mut fields = Vec::new();
                 for field in #ty::iter() {
-                    let field_val = field.to_string().trim_start_matches("'").trim_end_matches("'").to_string();
-                    fields.push((field_val.clone(), field_val));
+                    let key = <#ty>::to_select_list_key(&field);
+                    let label = match field {
+                        #(#labels),*
+                    };
+                    fields.push((key, label));
                 }
                 Ok(fields)
             }
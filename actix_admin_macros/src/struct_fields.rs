@@ -1,8 +1,8 @@
 use crate::attributes::derive_attr;
 use crate::model_fields::ModelField;
-use proc_macro2::{Span, TokenStream};
+use proc_macro2::{Group, Span, TokenStream, TokenTree};
 use quote::{quote, ToTokens};
-use syn::{DeriveInput, Fields, LitStr, Ident};
+use syn::{Attribute, DeriveInput, Fields, LitStr, Ident};
 
 pub fn get_fields_for_tokenstream(input: proc_macro::TokenStream) -> std::vec::Vec<ModelField> {
     let ast: DeriveInput = syn::parse(input).unwrap();
@@ -26,12 +26,47 @@ fn to_camelcase(s: &str) -> String {
     s.split("_").fold(String::new(), |a, b| capitalize_first_letter(&a) + &capitalize_first_letter(b))
 }
 
+/// Expands the switch `filter` to `filter = "eq"`, as bae only parses attributes without value as switches
+fn expand_filter_switch(attrs: &[Attribute]) -> Vec<Attribute> {
+    attrs
+        .iter()
+        .map(|attr| {
+            let mut attr = attr.clone();
+            if !attr.path.is_ident("actix_admin") {
+                return attr;
+            }
+            attr.tokens = attr.tokens
+                .into_iter()
+                .map(|token_tree| match token_tree {
+                    TokenTree::Group(group) => {
+                        let tokens: Vec<TokenTree> = group.stream().into_iter().collect();
+                        let mut expanded = TokenStream::new();
+                        for (i, token) in tokens.iter().enumerate() {
+                            expanded.extend(std::iter::once(token.clone()));
+                            let is_filter = matches!(token, TokenTree::Ident(ident) if ident == "filter");
+                            let has_value = matches!(tokens.get(i + 1), Some(TokenTree::Punct(punct)) if punct.as_char() == '=');
+                            if is_filter && !has_value {
+                                expanded.extend(quote! { = "eq" });
+                            }
+                        }
+                        let mut expanded_group = Group::new(group.delimiter(), expanded);
+                        expanded_group.set_span(group.span());
+                        TokenTree::Group(expanded_group)
+                    },
+                    token_tree => token_tree
+                })
+                .collect();
+            attr
+        })
+        .collect()
+}
+
 pub fn filter_fields(fields: &Fields) -> Vec<ModelField> {
     fields
         .iter()
         .filter_map(|field| {
             let actix_admin_attr =
-                derive_attr::ActixAdmin::try_from_attributes(&field.attrs).unwrap_or_default();
+                derive_attr::ActixAdmin::try_from_attributes(&expand_filter_switch(&field.attrs)).unwrap_or_default();
 
             if field.ident.is_some() {
                 let field_vis = field.vis.clone();
@@ -53,6 +88,18 @@ pub fn filter_fields(fields: &Fields) -> Vec<ModelField> {
                 let is_list_hide_column = actix_admin_attr
                     .clone()
                     .map_or(false, |attr| attr.list_hide_column.is_some());
+                let is_readonly_after_create = actix_admin_attr
+                    .clone()
+                    .map_or(false, |attr| attr.readonly_after_create.is_some());
+                let is_show_only = actix_admin_attr
+                    .clone()
+                    .map_or(false, |attr| attr.show_only.is_some());
+                let is_form_hidden = actix_admin_attr
+                    .clone()
+                    .map_or(false, |attr| attr.form_hidden.is_some());
+                let is_version = actix_admin_attr
+                    .clone()
+                    .map_or(false, |attr| attr.version.is_some());
                 let is_not_empty = actix_admin_attr
                     .clone()
                     .map_or(false, |attr| attr.not_empty.is_some());
@@ -71,6 +118,35 @@ pub fn filter_fields(fields: &Fields) -> Vec<ModelField> {
                         }
                     })
                 });
+                let label = actix_admin_attr.clone().map_or("".to_string(), |attr| {
+                    attr.label.map_or("".to_string(), |attr_field| {
+                        (LitStr::from(attr_field)).value()
+                    })
+                });
+                let help_text = actix_admin_attr.clone().map_or("".to_string(), |attr| {
+                    attr.help_text.map_or("".to_string(), |attr_field| {
+                        (LitStr::from(attr_field)).value()
+                    })
+                });
+                let placeholder = actix_admin_attr.clone().map_or("".to_string(), |attr| {
+                    attr.placeholder.map_or("".to_string(), |attr_field| {
+                        (LitStr::from(attr_field)).value()
+                    })
+                });
+                let renderer = actix_admin_attr.clone().map_or("".to_string(), |attr| {
+                    attr.renderer.map_or("".to_string(), |attr_field| {
+                        (LitStr::from(attr_field)).value()
+                    })
+                });
+                let filter = actix_admin_attr.clone().and_then(|attr| {
+                    attr.filter.map(|attr_field| {
+                        let filter = (LitStr::from(attr_field)).value();
+                        match filter.as_str() {
+                            "eq" | "contains" | "range" => filter,
+                            _ => panic!("unknown filter {}, expected eq, contains or range", filter)
+                        }
+                    })
+                });
                 let select_list = actix_admin_attr.clone().map_or("".to_string(), |attr| {
                     attr.select_list.map_or("".to_string(), |attr_field| {
                         (LitStr::from(attr_field)).value()
@@ -97,7 +173,16 @@ pub fn filter_fields(fields: &Fields) -> Vec<ModelField> {
                     not_empty: is_not_empty,
                     list_sort_position: list_sort_position,
                     list_hide_column: is_list_hide_column,
-                    list_regex_mask: list_regex_mask
+                    list_regex_mask: list_regex_mask,
+                    label: label,
+                    help_text: help_text,
+                    placeholder: placeholder,
+                    readonly_after_create: is_readonly_after_create,
+                    show_only: is_show_only,
+                    form_hidden: is_form_hidden || is_version,
+                    version: is_version,
+                    filter: filter,
+                    renderer: renderer
                 };
                 Some(model_field)
             } else {
@@ -179,15 +264,45 @@ pub fn get_match_name_to_column(fields: &Vec<ModelField>) -> Vec<TokenStream> {
     .collect::<Vec<_>>()
 }
 
+/// Conditions of the plain text search, which only applies to searchable text fields
 pub fn get_actix_admin_fields_searchable(fields: &Vec<ModelField>) -> Vec<TokenStream> {
     fields
         .iter()
-        .filter(|model_field| model_field.searchable)
+        .filter(|model_field| model_field.searchable && model_field.get_type_path_string() == "String")
         .map(|model_field| {
             let column_name = capitalize_first_letter(&model_field.ident.to_string());
             let column_ident = Ident::new(&column_name, Span::call_site());
             quote! {
-                .add(Column::#column_ident.contains(&search))
+                .add(Column::#column_ident.contains(&term.value))
+            }
+        })
+        .collect::<Vec<_>>()
+}
+
+pub fn get_fields_for_search(fields: &Vec<ModelField>) -> Vec<TokenStream> {
+    fields
+        .iter()
+        .filter(|model_field| model_field.searchable)
+        .map(|model_field| {
+            let ident_name = model_field.ident.to_string();
+            let column_ident = Ident::new(&to_camelcase(&ident_name), Span::call_site());
+            let ty = match &model_field.inner_type {
+                Some(inner_type) => inner_type.to_owned(),
+                None => model_field.ty.to_owned()
+            };
+
+            // types without a typed comparison, e.g. Decimal, enums or Json, can't be searched by value
+            match model_field.get_type_path_string().as_str() {
+                "i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "u64" | "f32" | "f64" | "String" | "bool" | "Date" | "DateTime" => {
+                    quote! {
+                        Some(#ident_name) => get_search_term_condition::<_, #ty>(Column::#column_ident, &term),
+                    }
+                },
+                _ => {
+                    quote! {
+                        Some(#ident_name) => Err(format!("Invalid search term {}: the field cannot be searched by value", term.raw)),
+                    }
+                }
             }
         })
         .collect::<Vec<_>>()
@@ -217,6 +332,27 @@ pub fn get_primary_key_field_name(fields: &Vec<ModelField>) -> String {
     primary_key_model_field.ident.to_string()
 }
 
+pub fn get_version_field(fields: &Vec<ModelField>) -> Option<&ModelField> {
+    fields.iter().find(|model_field| model_field.version)
+}
+
+pub fn get_column_for_field(model_field: &ModelField) -> TokenStream {
+    let column_ident = Ident::new(&to_camelcase(&model_field.ident.to_string()), Span::call_site());
+
+    quote! {
+        Column::#column_ident
+    }
+}
+
+pub fn get_primary_key_column(fields: &Vec<ModelField>) -> TokenStream {
+    let column_name_capitalized = to_camelcase(&get_primary_key_field_name(fields));
+    let column_ident = Ident::new(&column_name_capitalized, Span::call_site());
+
+    quote! {
+        Column::#column_ident
+    }
+}
+
 pub fn get_fields_for_from_model(fields: &Vec<ModelField>) -> Vec<TokenStream> {
     fields
         .iter()
@@ -225,8 +361,37 @@ pub fn get_fields_for_from_model(fields: &Vec<ModelField>) -> Vec<TokenStream> {
             let ident_name = model_field.ident.to_string();
             let ident = model_field.ident.to_owned();
 
-            match model_field.is_option() {
-                true => {
+            if model_field.is_select_list_key() {
+                let ty = model_field.inner_type.to_owned().unwrap_or(model_field.ty.to_owned());
+                return match model_field.is_option() {
+                    true => quote! {
+                        #ident_name => match &model.#ident {
+                            Some(val) => <#ty>::to_select_list_key(val),
+                            None => "".to_owned()
+                        }
+                    },
+                    false => quote! {
+                        #ident_name => <#ty>::to_select_list_key(&model.#ident)
+                    }
+                };
+            }
+
+            match (model_field.is_option(), model_field.is_json()) {
+                // json is kept as is, as the quotes of strings are part of the value
+                (true, true) => {
+                    quote! {
+                        #ident_name => match model.#ident {
+                            Some(val) => val.to_string(),
+                            None => "".to_owned()
+                        }
+                    }
+                }
+                (false, true) => {
+                    quote! {
+                        #ident_name => model.#ident.to_string()
+                    }
+                }
+                (true, false) => {
                     quote! {
                         #ident_name => match model.#ident {
                             Some(val) => val.to_string().trim_start_matches("'").trim_end_matches("'").to_string(),
@@ -234,7 +399,7 @@ pub fn get_fields_for_from_model(fields: &Vec<ModelField>) -> Vec<TokenStream> {
                         }
                     }
                 }
-                false => {
+                (false, false) => {
                     quote! {
                         #ident_name => model.#ident.to_string().trim_start_matches("'").trim_end_matches("'").to_string()
                     }
@@ -247,7 +412,7 @@ pub fn get_fields_for_from_model(fields: &Vec<ModelField>) -> Vec<TokenStream> {
 pub fn get_fields_for_validate_model(fields: &Vec<ModelField>) -> Vec<TokenStream> {
     fields
     .iter()
-    .filter(|model_field| !model_field.primary_key)
+    .filter(|model_field| !model_field.primary_key && !model_field.is_excluded_from_form())
     .map(|model_field| {
         let ident_name = model_field.ident.to_string();
         let ty = model_field.ty.to_owned();
@@ -262,16 +427,37 @@ pub fn get_fields_for_validate_model(fields: &Vec<ModelField>) -> Vec<TokenStrea
                     model.get_datetime(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).map_err(|err| errors.insert(#ident_name.to_string(), err)).ok()
                 }
             },
+            (_, "DateTimeWithTimeZone") => {
+                quote! {
+                    model.get_datetime_with_timezone(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).map_err(|err| errors.insert(#ident_name.to_string(), err)).ok()
+                }
+            },
             (_, "Date") => {
                 quote! {
                     model.get_date(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).map_err(|err| errors.insert(#ident_name.to_string(), err)).ok()
                 }
             },
+            (_, "Time" | "NaiveTime" | "chrono :: NaiveTime") => {
+                quote! {
+                    model.get_time(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).map_err(|err| errors.insert(#ident_name.to_string(), err)).ok()
+                }
+            },
             (_, "bool") => {
                 quote! {
                     model.get_bool(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).map_err(|err| errors.insert(#ident_name.to_string(), err)).ok()
                 }
             },
+            (_, _) if model_field.is_json() => {
+                quote! {
+                    model.get_json(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).map_err(|err| errors.insert(#ident_name.to_string(), err)).ok()
+                }
+            },
+            (_, _) if model_field.is_select_list_key() => {
+                let ty = model_field.inner_type.to_owned().unwrap_or(ty);
+                quote! {
+                    model.get_value_by(#ident_name, #is_option_or_string, #is_allowed_to_be_empty, <#ty>::from_select_list_key).map_err(|err| errors.insert(#ident_name.to_string(), err)).ok()
+                }
+            },
             // generic
             (true, _) => {
                 let inner_ty = model_field.inner_type.to_owned().unwrap();
@@ -286,7 +472,14 @@ pub fn get_fields_for_validate_model(fields: &Vec<ModelField>) -> Vec<TokenStrea
             }
         };
 
-        res
+        match model_field.readonly_after_create {
+            true => quote! {
+                if model.primary_key.is_none() {
+                    #res;
+                }
+            },
+            false => res
+        }
     })
     .collect::<Vec<_>>()
 }
@@ -295,7 +488,7 @@ pub fn get_fields_for_create_model(fields: &Vec<ModelField>) -> Vec<TokenStream>
     fields
         .iter()
         // TODO: filter id attr based on struct attr or sea_orm primary_key attr
-        .filter(|model_field| !model_field.primary_key)
+        .filter(|model_field| !model_field.primary_key && !model_field.is_excluded_from_form())
         .map(|model_field| {
             let ident_name = model_field.ident.to_string();
             let ident = model_field.ident.to_owned();
@@ -309,51 +502,103 @@ pub fn get_fields_for_create_model(fields: &Vec<ModelField>) -> Vec<TokenStream>
                 // is DateTime
                 (true , _, "DateTime") => {
                     quote! {
-                        #ident: Set(model.get_datetime(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap())
+                        Set(model.get_datetime(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap())
+                    }
+                },
+                (true , _, "DateTimeWithTimeZone") => {
+                    quote! {
+                        Set(model.get_datetime_with_timezone(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap())
                     }
                 },
                 (false , _, "DateTime") => {
                     quote! {
-                        #ident: Set(model.get_datetime(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap().unwrap())
+                        Set(model.get_datetime(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap().unwrap())
+                    }
+                },
+                (false , _, "DateTimeWithTimeZone") => {
+                    quote! {
+                        Set(model.get_datetime_with_timezone(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap().unwrap())
                     }
                 },
                 (true , _, "Date") => {
                     quote! {
-                        #ident: Set(model.get_date(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap())
+                        Set(model.get_date(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap())
+                    }
+                },
+                (true , _, "Time" | "NaiveTime" | "chrono :: NaiveTime") => {
+                    quote! {
+                        Set(model.get_time(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap())
                     }
                 },
                 (false , _, "Date") => {
                     quote! {
-                        #ident: Set(model.get_date(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap().unwrap())
+                        Set(model.get_date(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap().unwrap())
+                    }
+                },
+                (false , _, "Time" | "NaiveTime" | "chrono :: NaiveTime") => {
+                    quote! {
+                        Set(model.get_time(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap().unwrap())
                     }
                 },
                 (_ , _, "bool") => {
                     quote! {
-                        #ident: Set(model.get_bool(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap().unwrap())
+                        Set(model.get_bool(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap().unwrap())
+                    }
+                },
+                (true, _, _) if model_field.is_json() => {
+                    quote! {
+                        Set(model.get_json(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap())
+                    }
+                },
+                (false, _, _) if model_field.is_json() => {
+                    quote! {
+                        Set(model.get_json(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap().unwrap())
+                    }
+                },
+                (true, _, _) if model_field.is_select_list_key() => {
+                    let inner_ty = model_field.inner_type.to_owned().unwrap();
+                    quote! {
+                        Set(model.get_value_by(#ident_name, #is_option_or_string, #is_allowed_to_be_empty, <#inner_ty>::from_select_list_key).unwrap())
+                    }
+                },
+                (false, _, _) if model_field.is_select_list_key() => {
+                    quote! {
+                        Set(model.get_value_by(#ident_name, #is_option_or_string, #is_allowed_to_be_empty, <#ty>::from_select_list_key).unwrap().unwrap())
                     }
                 },
                 // Default fields
                 (true, _, _) => {
                     let inner_ty = model_field.inner_type.to_owned().unwrap();
                     quote! {
-                        #ident: Set(model.get_value::<#inner_ty>(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap())
+                        Set(model.get_value::<#inner_ty>(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap())
                     }
                 },
                 // is string which can be empty
                 (false, true, _) => {
                     quote! {
-                        #ident: Set(model.get_value::<#ty>(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap().unwrap_or(String::new()))
+                        Set(model.get_value::<#ty>(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap().unwrap_or(String::new()))
                     }
                 },
                 // no string
                 (false, false, _) => {
                     quote! {
-                        #ident: Set(model.get_value::<#ty>(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap().unwrap())
+                        Set(model.get_value::<#ty>(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap().unwrap())
                     }
                 }
             };
 
-            res
+            // fields which are read-only after creation are left untouched when an existing entity is converted
+            match model_field.readonly_after_create {
+                true => quote! {
+                    #ident: match model.primary_key {
+                        None => #res,
+                        Some(_) => sea_orm::ActiveValue::NotSet
+                    }
+                },
+                false => quote! {
+                    #ident: #res
+                }
+            }
         })
         .collect::<Vec<_>>()
 }
@@ -362,7 +607,9 @@ pub fn get_fields_for_edit_model(fields: &Vec<ModelField>) -> Vec<TokenStream> {
     fields
         .iter()
         // TODO: filter id attr based on struct attr or sea_orm primary_key attr
-        .filter(|model_field| !model_field.primary_key)
+        .filter(|model_field| {
+            !model_field.primary_key && !model_field.is_excluded_from_form() && !model_field.readonly_after_create
+        })
         .map(|model_field| {
             let ident_name = model_field.ident.to_string();
             let ident = model_field.ident.to_owned();
@@ -383,21 +630,62 @@ pub fn get_fields_for_edit_model(fields: &Vec<ModelField>) -> Vec<TokenStream> {
                         entity.#ident = Set(model.get_datetime(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap())
                     }
                 },
+                (true , _, "DateTimeWithTimeZone") => {
+                    quote! {
+                        entity.#ident = Set(model.get_datetime_with_timezone(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap())
+                    }
+                },
                 (false , _, "DateTime") => {
                     quote! {
                         entity.#ident = Set(model.get_datetime(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap().unwrap())
                     }
                 },
+                (false , _, "DateTimeWithTimeZone") => {
+                    quote! {
+                        entity.#ident = Set(model.get_datetime_with_timezone(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap().unwrap())
+                    }
+                },
                 (true , _, "Date") => {
                     quote! {
                         entity.#ident = Set(model.get_date(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap())
                     }
                 },
+                (true , _, "Time" | "NaiveTime" | "chrono :: NaiveTime") => {
+                    quote! {
+                        entity.#ident = Set(model.get_time(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap())
+                    }
+                },
                 (false , _, "Date") => {
                     quote! {
                         entity.#ident = Set(model.get_date(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap().unwrap())
                     }
                 },
+                (false , _, "Time" | "NaiveTime" | "chrono :: NaiveTime") => {
+                    quote! {
+                        entity.#ident = Set(model.get_time(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap().unwrap())
+                    }
+                },
+                (true, _, _) if model_field.is_json() => {
+                    quote! {
+                        entity.#ident = Set(model.get_json(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap())
+                    }
+                },
+                (false, _, _) if model_field.is_json() => {
+                    quote! {
+                        entity.#ident = Set(model.get_json(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap().unwrap())
+                    }
+                },
+                (true, _, _) if model_field.is_select_list_key() => {
+                    let inner_ty = model_field.inner_type.to_owned().unwrap();
+                    quote! {
+                        entity.#ident = Set(model.get_value_by(#ident_name, #is_option_or_string, #is_allowed_to_be_empty, <#inner_ty>::from_select_list_key).unwrap())
+                    }
+                },
+                (false, _, _) if model_field.is_select_list_key() => {
+                    quote! {
+                        entity.#ident = Set(model.get_value_by(#ident_name, #is_option_or_string, #is_allowed_to_be_empty, <#ty>::from_select_list_key).unwrap().unwrap())
+                    }
+                },
                 (true, _, _) => {
                     let inner_ty = model_field.inner_type.to_owned().unwrap();
                     quote! {
@@ -420,3 +708,100 @@ pub fn get_fields_for_edit_model(fields: &Vec<ModelField>) -> Vec<TokenStream> {
         })
         .collect::<Vec<_>>()
 }
+
+/// Name and filter type of the filter of a field, a range is filtered with a from and a to input
+fn get_filter_of_field(model_field: &ModelField) -> Option<(String, TokenStream)> {
+    let filter_type = match (model_field.get_filter_kind()?, model_field.get_type_path_string().as_str()) {
+        ("contains", _) => quote! { ActixAdminModelFilterType::Text },
+        ("range", "Date" | "NaiveDate" | "chrono :: NaiveDate") => quote! { ActixAdminModelFilterType::DateRange },
+        ("range", "DateTime" | "NaiveDateTime" | "chrono :: NaiveDateTime" | "DateTimeWithTimeZone") => quote! { ActixAdminModelFilterType::DateTimeRange },
+        ("range", "i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "u64") => quote! { ActixAdminModelFilterType::IntegerRange },
+        ("range", _) => quote! { ActixAdminModelFilterType::NumberRange },
+        (_, _) if !model_field.select_list.is_empty() => quote! { ActixAdminModelFilterType::SelectList },
+        (_, "bool") => quote! { ActixAdminModelFilterType::Checkbox },
+        (_, "Date" | "NaiveDate" | "chrono :: NaiveDate") => quote! { ActixAdminModelFilterType::Date },
+        (_, "DateTime" | "NaiveDateTime" | "chrono :: NaiveDateTime" | "DateTimeWithTimeZone") => quote! { ActixAdminModelFilterType::DateTime },
+        (_, _) => quote! { ActixAdminModelFilterType::Text }
+    };
+
+    Some((model_field.ident.to_string(), filter_type))
+}
+
+pub fn get_fields_for_filter(fields: &Vec<ModelField>) -> Vec<TokenStream> {
+    fields
+        .iter()
+        .filter_map(|model_field| {
+            let (name, filter_type) = get_filter_of_field(model_field)?;
+            let values = match model_field.select_list.is_empty() {
+                true => quote! { None },
+                false => {
+                    let select_list_ident = Ident::new(&model_field.select_list, Span::call_site());
+                    quote! { #select_list_ident::get_key_value(db).await.ok() }
+                }
+            };
+
+            Some(quote! {
+                hashmap.insert(
+                    #name.to_string(),
+                    ActixAdminViewModelFilter {
+                        name: #name.to_string(),
+                        value: None,
+                        value_to: None,
+                        values: #values,
+                        filter_type: Some(#filter_type)
+                    }
+                );
+            })
+        })
+        .collect::<Vec<_>>()
+}
+
+pub fn get_fields_for_filter_query(fields: &Vec<ModelField>) -> Vec<TokenStream> {
+    fields
+        .iter()
+        .filter_map(|model_field| {
+            let (name, _) = get_filter_of_field(model_field)?;
+            let column = get_column_for_field(model_field);
+            let ty = model_field.inner_type.to_owned().unwrap_or(model_field.ty.to_owned());
+
+            // invalid values are ignored like values of filters which are not set
+            let parsed_value = match model_field.get_type_path_string().as_str() {
+                _ if model_field.is_select_list_key() => quote! { <#ty>::from_select_list_key(&value) },
+                "String" => quote! { Some(value) },
+                "bool" => quote! {
+                    match value.as_str() {
+                        "1" | "true" => Some(true),
+                        "0" | "false" => Some(false),
+                        _ => None
+                    }
+                },
+                "DateTime" | "NaiveDateTime" | "chrono :: NaiveDateTime" => quote! { actix_admin::timezone::parse_naive_datetime(&value) },
+                // the list view converts the values to datetimes with offset in the timezone of the user
+                "DateTimeWithTimeZone" => quote! { actix_admin::timezone::parse_datetime_with_timezone(&value) },
+                _ => quote! { value.parse::<#ty>().ok() }
+            };
+
+            let apply_condition = |value_name: String, condition: TokenStream| quote! {
+                if let Some(condition) = filter_values.get(#value_name).cloned().flatten().and_then(|value| #condition) {
+                    query = query.filter(condition);
+                }
+            };
+            let conditions = match model_field.get_filter_kind() {
+                Some("contains") => apply_condition(name.to_string(), quote! { Some(#column.contains(&value)) }),
+                Some("range") => {
+                    let from = apply_condition(format!("{}_from", name), quote! { (#parsed_value).map(|value| #column.gte(value)) });
+                    let to = apply_condition(format!("{}_to", name), quote! { (#parsed_value).map(|value| #column.lte(value)) });
+                    quote! { #from #to }
+                },
+                _ => apply_condition(name.to_string(), quote! { (#parsed_value).map(|value| #column.eq(value)) })
+            };
+
+            // filters of the entity with the same name replace the declared filter
+            Some(quote! {
+                if !filters.iter().any(|filter| filter.name == #name) && !range_filters.iter().any(|filter| filter.name == #name) {
+                    #conditions
+                }
+            })
+        })
+        .collect::<Vec<_>>()
+}
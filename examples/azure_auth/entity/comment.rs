@@ -50,4 +50,6 @@ impl ActixAdminModelValidationTrait<ActiveModel> for Entity {
     }
 }
 
-impl ActixAdminModelFilterTrait<Entity> for Entity {}
\ No newline at end of file
+impl ActixAdminModelFilterTrait<Entity> for Entity {}
+impl ActixAdminComputedColumnTrait<Entity> for Entity {}
+impl ActixAdminModelHooksTrait for Entity {}
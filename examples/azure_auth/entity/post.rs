@@ -3,7 +3,6 @@ use serde::{Deserialize, Serialize};
 use actix_admin::prelude::*; 
 use std::fmt;
 use std::fmt::Display;
-use std::str::FromStr;
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Deserialize, Serialize, DeriveActixAdmin, DeriveActixAdminViewModel, DeriveActixAdminModel, DeriveActixAdminModelSelectList)]
 #[sea_orm(table_name = "post")]
@@ -55,18 +54,8 @@ pub enum Tea {
     BreakfastTea,
 }
 
-impl FromStr for Tea {
-    type Err = ();
-
-    fn from_str(input: &str) -> Result<Tea, Self::Err> {
-        match input {
-            "EverydayTea"  => Ok(Tea::EverydayTea),
-            "BreakfastTea"  => Ok(Tea::BreakfastTea),
-            _      => Err(()),
-        }
-    }
-}
-
 impl ActixAdminModelValidationTrait<ActiveModel> for Entity {}
 
-impl ActixAdminModelFilterTrait<Entity> for Entity {}
\ No newline at end of file
+impl ActixAdminModelFilterTrait<Entity> for Entity {}
+impl ActixAdminComputedColumnTrait<Entity> for Entity {}
+impl ActixAdminModelHooksTrait for Entity {}
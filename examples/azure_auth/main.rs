@@ -81,10 +81,16 @@ fn create_actix_admin_builder() -> ActixAdminBuilder {
              let user_info = session.get::<UserInfo>("user_info").unwrap();
              user_info.is_some()
         }),
+        user_identifier: None,
         login_link: Some("/azure-auth/login".to_string()),
         logout_link: Some("/azure-auth/logout".to_string()),
         file_upload_directory: "./file_uploads",
-        navbar_title: "ActixAdmin Example"
+        navbar_title: "ActixAdmin Example",
+        default_locale: "en",
+        default_timezone: Tz::UTC,
+        asset_urls: None,
+        theme: ActixAdminTheme::default(),
+        debug_mode: false
     };
 
     let mut admin_builder = ActixAdminBuilder::new(configuration);
@@ -1,8 +1,7 @@
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 use actix_admin::prelude::*;
-use super::{Post, post};
-use chrono::NaiveDateTime;
+use super::Post;
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Deserialize, Serialize, DeriveActixAdmin, DeriveActixAdminModel, DeriveActixAdminViewModel)]
 #[sea_orm(table_name = "comment")]
@@ -13,12 +12,14 @@ pub struct Model {
     pub id: i32,
     pub comment: String,
     #[sea_orm(column_type = "Text")]
-    #[actix_admin(html_input_type = "email", list_regex_mask= "^([a-zA-Z]*)")]
+    #[actix_admin(html_input_type = "email", list_regex_mask= "^([a-zA-Z]*)", filter = "contains")]
     pub user: String,
     #[sea_orm(column_type = "DateTime")]
+    #[actix_admin(filter = "range")]
     pub insert_date: DateTime,
+    #[actix_admin(filter)]
     pub is_visible: bool,
-    #[actix_admin(select_list="Post")]
+    #[actix_admin(select_list="Post", filter)]
     pub post_id: Option<i32>,
     pub my_decimal: Decimal
 }
@@ -52,53 +53,6 @@ impl ActixAdminModelValidationTrait<ActiveModel> for Entity {
     }
 }
 
-#[async_trait]
-impl ActixAdminModelFilterTrait<Entity> for Entity {
-    fn get_filter() -> Vec<ActixAdminModelFilter<Entity>> {
-        vec![
-            ActixAdminModelFilter::<Entity> {
-                name: "User".to_string(),
-                filter_type: ActixAdminModelFilterType::Text,
-                filter: |q: sea_orm::Select<Entity>, v| -> sea_orm::Select<Entity> {
-                    q.apply_if(v, | query, val: String| query.filter(Column::User.eq(val)))
-                },
-                values: None
-            },
-            ActixAdminModelFilter::<Entity> {
-                name: "Insert Date After".to_string(),
-                filter_type: ActixAdminModelFilterType::DateTime,
-                filter: |q: sea_orm::Select<Entity>, v| -> sea_orm::Select<Entity> {
-                    q.apply_if(v, | query, val: String| query.filter(Column::InsertDate.gte(NaiveDateTime::parse_from_str(&val, "%Y-%m-%dT%H:%M").unwrap())))
-                },
-                values: None
-            },
-            ActixAdminModelFilter::<Entity> {
-                name: "Is Visible".to_string(),
-                filter_type: ActixAdminModelFilterType::Checkbox,
-                filter: |q: sea_orm::Select<Entity>, v| -> sea_orm::Select<Entity> {
-                    q.apply_if(v, | query, val: String| query.filter(Column::IsVisible.eq(val)))
-                },
-                values: None
-            },
-            ActixAdminModelFilter::<Entity> {
-                name: "Post".to_string(),
-                filter_type: ActixAdminModelFilterType::SelectList,
-                filter: |q: sea_orm::Select<Entity>, v| -> sea_orm::Select<Entity> {
-                    q.apply_if(v, | query, val: String| query.filter(Column::PostId.eq(val)))
-                },
-                values: None
-            }
-        ]
-    }
-
-    async fn get_filter_values(filter: &ActixAdminModelFilter<Entity>, db: &DatabaseConnection) -> Option<Vec<(String, String)>> { 
-        match filter.name.as_str() {
-            "Post" => Some({
-                Post::find().order_by_asc(post::Column::Id).all(db).await.unwrap()
-                    .iter().map(|p| (p.id.to_string(), p.title.to_string())).collect()
-            }),
-            _ => None
-        }
-    }
-}
-
+impl ActixAdminModelFilterTrait<Entity> for Entity {}
+impl ActixAdminComputedColumnTrait<Entity> for Entity {}
+impl ActixAdminModelHooksTrait for Entity {}
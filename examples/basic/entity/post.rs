@@ -1,10 +1,10 @@
 use actix_admin::model::ActixAdminModelFilterTrait;
 use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::{Expr, Query, SimpleExpr, SubQueryStatement};
 use serde::{Deserialize, Serialize};
 use actix_admin::prelude::*; 
 use std::fmt;
 use std::fmt::Display;
-use std::str::FromStr;
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Deserialize, Serialize, DeriveActixAdmin, DeriveActixAdminViewModel, DeriveActixAdminModel, DeriveActixAdminModelSelectList)]
 #[sea_orm(table_name = "post")]
@@ -13,7 +13,7 @@ pub struct Model {
     #[serde(skip_deserializing)]
     #[actix_admin(primary_key)]
     pub id: i32,
-    #[actix_admin(searchable, not_empty)]
+    #[actix_admin(searchable, not_empty, label = "Headline", placeholder = "A catchy headline")]
     pub title: String,
     #[sea_orm(column_type = "Text")]
     #[actix_admin(searchable, textarea, list_hide_column)]
@@ -25,7 +25,7 @@ pub struct Model {
     #[sea_orm(column_type = "Date")]
     #[actix_admin(list_sort_position="1")]
     pub insert_date: Date,
-    #[actix_admin(file_upload)]
+    #[actix_admin(file_upload, help_text = "The file is stored in the upload directory")]
     pub attachment: Option<String>
 }
 
@@ -60,18 +60,27 @@ pub enum Tea {
     BreakfastTea,
 }
 
-impl FromStr for Tea {
-    type Err = ();
+impl ActixAdminModelValidationTrait<ActiveModel> for Entity {}
 
-    fn from_str(input: &str) -> Result<Tea, Self::Err> {
-        match input {
-            "EverydayTea"  => Ok(Tea::EverydayTea),
-            "BreakfastTea"  => Ok(Tea::BreakfastTea),
-            _      => Err(()),
-        }
+impl ActixAdminModelFilterTrait<Entity> for Entity {}
+
+impl ActixAdminComputedColumnTrait<Entity> for Entity {
+    fn get_computed_columns() -> Vec<ActixAdminComputedColumn<Entity>> {
+        let comment_count = Query::select()
+            .expr(Expr::col(super::comment::Column::Id).count())
+            .from(super::comment::Entity)
+            .and_where(Expr::col((super::comment::Entity, super::comment::Column::PostId)).equals((Entity, Column::Id)))
+            .to_owned();
+
+        vec![
+            ActixAdminComputedColumn {
+                name: "comment_count".to_string(),
+                label: Some("Comments".to_string()),
+                field_type: ActixAdminViewModelFieldType::Number,
+                value: ActixAdminComputedValue::Expr(SimpleExpr::SubQuery(None, Box::new(SubQueryStatement::SelectStatement(comment_count))))
+            }
+        ]
     }
 }
 
-impl ActixAdminModelValidationTrait<ActiveModel> for Entity {}
-
-impl ActixAdminModelFilterTrait<Entity> for Entity {}
\ No newline at end of file
+impl ActixAdminModelHooksTrait for Entity {}
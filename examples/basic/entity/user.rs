@@ -19,4 +19,6 @@ pub enum Relation {}
 
 impl ActixAdminModelValidationTrait<ActiveModel> for Entity {}
 
-impl ActixAdminModelFilterTrait<Entity> for Entity {}
\ No newline at end of file
+impl ActixAdminModelFilterTrait<Entity> for Entity {}
+impl ActixAdminComputedColumnTrait<Entity> for Entity {}
+impl ActixAdminModelHooksTrait for Entity {}
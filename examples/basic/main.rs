@@ -11,10 +11,16 @@ fn create_actix_admin_builder() -> ActixAdminBuilder {
     let configuration = ActixAdminConfiguration {
         enable_auth: false,
         user_is_logged_in: None,
+        user_identifier: None,
         login_link: None,
         logout_link: None,
         file_upload_directory: "./file_uploads",
-        navbar_title: "ActixAdmin Example"
+        navbar_title: "ActixAdmin Example",
+        default_locale: "en",
+        default_timezone: Tz::UTC,
+        asset_urls: None,
+        theme: ActixAdminTheme::default(),
+        debug_mode: cfg!(debug_assertions)
     };
 
     let mut admin_builder = ActixAdminBuilder::new(configuration);
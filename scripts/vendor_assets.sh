@@ -0,0 +1,25 @@
+#!/bin/sh
+# Downloads the frontend libraries which are embedded with the feature `embedded-assets` into
+# src/static/<library>/<version>/<file>. The versions, CDN urls and files are read from
+# src/static_libraries.rs, change a version there and run this script to vendor the new files.
+set -e
+
+SRC_DIR="$(dirname "$0")/../src"
+LIBRARIES="$SRC_DIR/static_libraries.rs"
+
+# prints the string of a macro in static_libraries.rs, with the version macro replaced by $2
+macro_value() {
+    grep -A2 "macro_rules! $1 " "$LIBRARIES" | tail -n 1 \
+        | sed "s/[a-z_]*_version!()/\"$2\"/" | grep -o '"[^"]*"' | tr -d '"\n'
+}
+
+grep -o '^ *("[^"]*", [a-z_]*_version!(), "[^"]*"' "$LIBRARIES" \
+    | sed 's/^ *("\([^"]*\)", \([a-z_]*\)_version!(), "\([^"]*\)"/\1 \2 \3/' \
+    | while read -r library prefix file; do
+        version="$(macro_value "${prefix}_version")"
+        cdn_url="$(macro_value "${prefix}_cdn_url" "$version")"
+        target="$SRC_DIR/static/$library/$version$file"
+        mkdir -p "$(dirname "$target")"
+        curl -fsSL "$cdn_url$file" -o "$target"
+        echo "$target"
+    done
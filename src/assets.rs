@@ -0,0 +1,92 @@
+use actix_web::http::header::{CacheControl, CacheDirective, ContentType};
+use actix_web::{web, HttpResponse};
+use serde_derive::Serialize;
+
+use crate::ActixAdminConfiguration;
+
+/// Path of the static route under the admin scope which serves the embedded assets
+pub const ASSETS_PATH: &str = "/admin/static";
+
+include!("static_libraries.rs");
+
+/// Scripts and styles of actix-admin as path below the static route, mime type and content. The paths
+/// contain the version of the asset, so responses are cached by browsers without revalidation.
+const ADMIN_ASSETS: &[(&str, &str, &[u8])] = &[
+    (concat!("actix_admin/", env!("CARGO_PKG_VERSION"), "/actix_admin.js"), "application/javascript", include_bytes!("static/actix_admin/actix_admin.js")),
+    (concat!("actix_admin/", env!("CARGO_PKG_VERSION"), "/actix_admin.css"), "text/css", include_bytes!("static/actix_admin/actix_admin.css")),
+];
+
+// the files of the libraries are vendored by scripts/vendor_assets.sh
+#[cfg(feature = "embedded-assets")]
+macro_rules! embedded_library_files {
+    ($(($library:literal, $version:expr, $file:literal, $mime_type:literal)),*) => {
+        &[$((
+            concat!($library, "/", $version, $file),
+            $mime_type,
+            include_bytes!(concat!("static/", $library, "/", $version, $file))
+        )),*]
+    };
+}
+
+/// Frontend libraries embedded with the feature `embedded-assets`, like the [ADMIN_ASSETS]
+#[cfg(feature = "embedded-assets")]
+const LIBRARY_ASSETS: &[(&str, &str, &[u8])] = with_library_files!(embedded_library_files);
+#[cfg(not(feature = "embedded-assets"))]
+const LIBRARY_ASSETS: &[(&str, &str, &[u8])] = &[];
+
+/// Path of the scripts and styles of actix-admin itself, which are always served locally
+pub fn get_admin_assets_path() -> String {
+    format!("{}/actix_admin/{}", ASSETS_PATH, env!("CARGO_PKG_VERSION"))
+}
+
+/// URLs of the frontend libraries which are loaded by the built-in templates
+#[derive(Clone, Debug, Serialize)]
+pub struct ActixAdminAssetUrls {
+    pub bulma_css: String,
+    pub font_awesome_css: String,
+    pub htmx_js: String,
+}
+
+impl ActixAdminAssetUrls {
+    /// URLs of the assets which are embedded with the feature `embedded-assets`
+    pub fn embedded() -> Self {
+        ActixAdminAssetUrls {
+            bulma_css: format!(concat!("{}/bulma/", bulma_version!(), "/css/bulma.min.css"), ASSETS_PATH),
+            font_awesome_css: format!(concat!("{}/font-awesome/", font_awesome_version!(), "/css/all.min.css"), ASSETS_PATH),
+            htmx_js: format!(concat!("{}/htmx.org/", htmx_version!(), "/htmx.min.js"), ASSETS_PATH),
+        }
+    }
+
+    /// URLs of the assets on public CDNs
+    pub fn cdn() -> Self {
+        ActixAdminAssetUrls {
+            bulma_css: concat!(bulma_cdn_url!(), "/css/bulma.min.css").to_string(),
+            font_awesome_css: concat!(font_awesome_cdn_url!(), "/css/all.min.css").to_string(),
+            htmx_js: concat!(htmx_cdn_url!(), "/htmx.min.js").to_string(),
+        }
+    }
+}
+
+/// Returns the asset urls of the configuration, or the embedded assets if none are configured.
+/// Without the feature `embedded-assets` the assets are loaded from the public CDNs.
+pub fn get_asset_urls(configuration: &ActixAdminConfiguration) -> ActixAdminAssetUrls {
+    match &configuration.asset_urls {
+        Some(asset_urls) => asset_urls.clone(),
+        None if cfg!(feature = "embedded-assets") => ActixAdminAssetUrls::embedded(),
+        None => ActixAdminAssetUrls::cdn(),
+    }
+}
+
+pub async fn serve_asset(path: web::Path<String>) -> HttpResponse {
+    match ADMIN_ASSETS.iter().chain(LIBRARY_ASSETS).find(|(asset_path, _, _)| *asset_path == path.as_str()) {
+        Some((_, mime_type, content)) => HttpResponse::Ok()
+            .insert_header(ContentType(mime_type.parse().unwrap()))
+            .insert_header(CacheControl(vec![
+                CacheDirective::Public,
+                CacheDirective::MaxAge(31_536_000),
+                CacheDirective::Extension("immutable".to_string(), None),
+            ]))
+            .body(*content),
+        None => HttpResponse::NotFound().finish(),
+    }
+}
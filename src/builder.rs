@@ -1,14 +1,24 @@
 use crate::{prelude::*, ActixAdminMenuElement, routes::delete_file};
+use crate::i18n::{get_builtin_locales, register_tera_filters};
+use crate::assets::serve_asset;
+use crate::renderer::register_renderer_filter;
 use actix_web::{web, Route };
 use tera::Tera;
 use std::collections::HashMap;
 use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use crate::routes::{
-    create_get, create_post, delete, delete_many, edit_get, edit_post, index, list, not_found, show, download
+    create_get, create_post, delete, delete_many, edit_get, edit_post, index, list, not_found, show, download,
+    save_view, delete_saved_view, global_search, search_entity, set_locale, set_timezone
 };
 use std::hash::BuildHasher;
 use tera::{to_value, try_get_value, Result};
 
+/// Prefix under which the built-in templates stay available when they are overridden,
+/// e.g. `{% extends "actix_admin/base.html" %}`
+pub const BUILTIN_TEMPLATE_PREFIX: &str = "actix_admin/";
+
 /// Represents a builder entity which helps generating the ActixAdmin configuration
 pub struct ActixAdminBuilder {
     pub scopes: HashMap<String, actix_web::Scope>,
@@ -66,6 +76,11 @@ pub trait ActixAdminBuilderTrait {
         add_to_menu: bool,
     );
     fn add_custom_handler_for_index(&mut self, route: Route);
+    fn add_saved_view_store<S: ActixAdminSavedViewStoreTrait + 'static>(&mut self, store: S);
+    fn add_locale(&mut self, locale_code: &str, locale: ActixAdminLocale);
+    fn add_renderer(&mut self, name: &str, renderer: ActixAdminRenderer);
+    fn add_template(&mut self, name: &str, content: &str) -> tera::Result<()>;
+    fn add_template_dir(&mut self, path: &str) -> tera::Result<()>;
     fn get_scope(self) -> actix_web::Scope;
     fn get_actix_admin(&self) -> ActixAdmin;
 }
@@ -82,6 +97,7 @@ fn get_html_input_class<S: BuildHasher>(
     );
     let html_input_type = match field.field_type {
         ActixAdminViewModelFieldType::TextArea => "textarea",
+        ActixAdminViewModelFieldType::Json => "textarea is-family-monospace",
         ActixAdminViewModelFieldType::Checkbox => "checkbox",
         _ => "input",
     };
@@ -103,6 +119,19 @@ fn get_icon<S: BuildHasher>(
     Ok(to_value(font_awesome_icon).unwrap())
 }
 
+fn pretty_json<S: BuildHasher>(
+    value: &tera::Value,
+    _: &HashMap<String, tera::Value, S>,
+) -> Result<tera::Value> {
+    let json = try_get_value!("pretty_json", "value", String, value);
+    let pretty_json = serde_json::from_str::<serde_json::Value>(&json)
+        .ok()
+        .and_then(|json| serde_json::to_string_pretty(&json).ok())
+        .unwrap_or(json);
+
+    Ok(to_value(pretty_json).unwrap())
+}
+
 fn get_regex_val<S: BuildHasher>(
     value: &tera::Value,
     args: &HashMap<String, tera::Value, S>,
@@ -144,7 +173,10 @@ fn get_html_input_type<S: BuildHasher>(
 
     let html_input_type = match field.field_type {
         ActixAdminViewModelFieldType::Text => "text",
+        ActixAdminViewModelFieldType::Number => "number",
+        ActixAdminViewModelFieldType::Time => "time",
         ActixAdminViewModelFieldType::DateTime => "datetime-local",
+        ActixAdminViewModelFieldType::DateTimeWithTimeZone => "datetime-local",
         ActixAdminViewModelFieldType::Date => "date",
         ActixAdminViewModelFieldType::Checkbox => "checkbox",
         ActixAdminViewModelFieldType::FileUpload => "file",
@@ -155,11 +187,12 @@ fn get_html_input_type<S: BuildHasher>(
 }
 
 fn get_tera() -> Tera {
-    let mut tera = Tera::new(concat!(env!("CARGO_MANIFEST_DIR"), "/src/templates/*.html")).unwrap();
+    let mut tera = Tera::default();
     tera.register_filter("get_html_input_type", get_html_input_type);
     tera.register_filter("get_html_input_class", get_html_input_class);
     tera.register_filter("get_icon", get_icon);
     tera.register_filter("get_regex_val", get_regex_val);
+    tera.register_filter("pretty_json", pretty_json);
 
     let list_html = include_str!("templates/list.html");
     let create_or_edit_html = include_str!("templates/create_or_edit.html");
@@ -169,6 +202,9 @@ fn get_tera() -> Tera {
     let loader_html = include_str!("templates/loader.html");
     let navbar_html = include_str!("templates/navbar.html");
     let not_found_html = include_str!("templates/not_found.html");
+    let notifications_html = include_str!("templates/notifications.html");
+    let error_html = include_str!("templates/error.html");
+    let search_html = include_str!("templates/search.html");
     let show_html = include_str!("templates/show.html");
     let unauthorized_html = include_str!("templates/unauthorized.html");
 
@@ -177,7 +213,7 @@ fn get_tera() -> Tera {
     let input_html = include_str!("templates/form_elements/input.html");
     let selectlist_html = include_str!("templates/form_elements/selectlist.html");
 
-    let _res = tera.add_raw_templates(vec![
+    let builtin_templates = vec![
         ("base.html", base_html),
         ("list.html", list_html),
         ("create_or_edit.html", create_or_edit_html),
@@ -186,25 +222,70 @@ fn get_tera() -> Tera {
         ("loader.html", loader_html),
         ("navbar.html", navbar_html),
         ("not_found.html", not_found_html),
+        ("notifications.html", notifications_html),
+        ("error.html", error_html),
+        ("search.html", search_html),
         ("show.html",show_html),
         ("unauthorized.html", unauthorized_html),
         // form elements
         ("form_elements/checkbox.html", checkbox_html),
         ("form_elements/input.html", input_html),
         ("form_elements/selectlist.html", selectlist_html),
-    ]);
+    ];
+
+    // the built-in templates stay available with a prefix for templates which override and extend them
+    let prefixed_templates: Vec<(String, &str)> = builtin_templates
+        .iter()
+        .map(|(name, content)| (format!("{}{}", BUILTIN_TEMPLATE_PREFIX, name), *content))
+        .collect();
+    let _res = tera.add_raw_templates(
+        builtin_templates
+            .into_iter()
+            .map(|(name, content)| (name.to_string(), content))
+            .chain(prefixed_templates),
+    );
 
     tera
 }
 
+fn get_template_files(root: &Path, dir: &Path, files: &mut Vec<(PathBuf, String)>) -> std::io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            get_template_files(root, &path, files)?;
+        } else if path.extension().is_some_and(|ext| ext == "html") {
+            let name = path
+                .strip_prefix(root)
+                .unwrap_or(&path)
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            files.push((path, name));
+        }
+    }
+
+    Ok(())
+}
+
 impl ActixAdminBuilderTrait for ActixAdminBuilder {
     fn new(configuration: ActixAdminConfiguration) -> Self {
+        let locales = get_builtin_locales();
+        let mut tera = get_tera();
+        register_tera_filters(&mut tera, Arc::new(locales.clone()));
+        register_renderer_filter(&mut tera, Arc::new(HashMap::new()));
+
         ActixAdminBuilder {
             actix_admin: ActixAdmin {
                 entity_names: HashMap::new(),
                 view_models: HashMap::new(),
                 configuration: configuration,
-                tera: get_tera()
+                tera,
+                saved_view_store: None,
+                count_cache: ActixAdminCountCache::default(),
+                search_functions: HashMap::new(),
+                locales,
+                renderers: HashMap::new()
             },
             custom_routes: Vec::new(),
             scopes: HashMap::new(),
@@ -239,6 +320,8 @@ impl ActixAdminBuilderTrait for ActixAdminBuilder {
                 .route("/show/{id}", web::get().to(show::<E>))
                 .route("/file/{id}/{column_name}", web::get().to(download::<E>))
                 .route("/file/{id}/{column_name}", web::delete().to(delete_file::<E>))
+                .route("/saved_view", web::post().to(save_view::<E>))
+                .route("/saved_view/{id}", web::delete().to(delete_saved_view::<E>))
                 .default_service(web::to(not_found))
             );
 
@@ -262,13 +345,43 @@ impl ActixAdminBuilderTrait for ActixAdminBuilder {
         }
 
         let key = E::get_entity_name();
-        self.actix_admin.view_models.insert(key, view_model.clone());
+        self.actix_admin.view_models.insert(key.clone(), view_model.clone());
+        self.actix_admin.search_functions.insert(key, search_entity::<E>);
     }
 
     fn add_custom_handler_for_index(&mut self, route: Route) {
         self.custom_index = Some(route);
     }
 
+    fn add_saved_view_store<S: ActixAdminSavedViewStoreTrait + 'static>(&mut self, store: S) {
+        self.actix_admin.saved_view_store = Some(Arc::new(store));
+    }
+
+    fn add_locale(&mut self, locale_code: &str, locale: ActixAdminLocale) {
+        self.actix_admin.locales.insert(locale_code.to_string(), locale);
+        register_tera_filters(&mut self.actix_admin.tera, Arc::new(self.actix_admin.locales.clone()));
+    }
+
+    fn add_renderer(&mut self, name: &str, renderer: ActixAdminRenderer) {
+        self.actix_admin.renderers.insert(name.to_string(), renderer);
+        register_renderer_filter(&mut self.actix_admin.tera, Arc::new(self.actix_admin.renderers.clone()));
+    }
+
+    fn add_template(&mut self, name: &str, content: &str) -> tera::Result<()> {
+        self.actix_admin.tera.add_raw_template(name, content)
+    }
+
+    fn add_template_dir(&mut self, path: &str) -> tera::Result<()> {
+        let root = Path::new(path);
+        let mut files = Vec::new();
+        get_template_files(root, root, &mut files)
+            .map_err(|err| tera::Error::chain(format!("Failed to read template directory {}", path), err))?;
+
+        self.actix_admin.tera.add_template_files(
+            files.iter().map(|(file, name)| (file, Some(name.as_str())))
+        )
+    }
+
     fn add_custom_handler_to_category(
         &mut self,
         menu_element_name: &str,
@@ -382,6 +495,10 @@ impl ActixAdminBuilderTrait for ActixAdminBuilder {
         };
         let mut admin_scope = web::scope("/admin")
             .route("/", index_handler)
+            .route("/search", web::get().to(global_search))
+            .route("/locale/{locale}", web::post().to(set_locale))
+            .route("/timezone/{timezone:.*}", web::post().to(set_timezone))
+            .route("/static/{path:.*}", web::get().to(serve_asset))
             .default_service(web::to(not_found));
 
         for (_entity, scope) in self.scopes {
@@ -0,0 +1,152 @@
+use futures_util::future::BoxFuture;
+use sea_orm::sea_query::{Expr, Order, SimpleExpr};
+use sea_orm::{
+    ConnectionTrait, DatabaseConnection, EntityTrait, FromQueryResult, QueryOrder, QueryResult,
+    QuerySelect, QueryTrait, Select,
+};
+use std::collections::HashMap;
+
+use crate::view_model::{ActixAdminViewModelField, ActixAdminViewModelFieldType};
+use crate::{ActixAdminError, ActixAdminModel, SortOrder};
+
+/// Computes the value of a computed column from an entity, e.g. a formatted full name
+pub type ActixAdminComputedFn<E> = for<'a> fn(&'a DatabaseConnection, &'a <E as EntityTrait>::Model) -> ActixAdminComputedFuture<'a>;
+pub type ActixAdminComputedFuture<'a> = BoxFuture<'a, Result<String, ActixAdminError>>;
+
+const COMPUTED_COLUMN_PREFIX: &str = "actix_admin_computed_";
+
+pub enum ActixAdminComputedValue<E: EntityTrait> {
+    /// SQL expression which is selected together with the entity, the column can be sorted
+    Expr(SimpleExpr),
+    /// Async function which is called for each entity after it was fetched
+    Fn(ActixAdminComputedFn<E>),
+}
+
+/// A display-only column of the list and show view which does not exist in the database
+pub struct ActixAdminComputedColumn<E: EntityTrait> {
+    pub name: String,
+    pub label: Option<String>,
+    pub field_type: ActixAdminViewModelFieldType,
+    pub value: ActixAdminComputedValue<E>,
+}
+
+impl<E: EntityTrait> ActixAdminComputedColumn<E> {
+    pub fn is_sortable(&self) -> bool {
+        matches!(self.value, ActixAdminComputedValue::Expr(_))
+    }
+
+    fn get_alias(&self) -> String {
+        format!("{}{}", COMPUTED_COLUMN_PREFIX, self.name)
+    }
+}
+
+impl<E: EntityTrait> From<&ActixAdminComputedColumn<E>> for ActixAdminViewModelField {
+    fn from(column: &ActixAdminComputedColumn<E>) -> Self {
+        ActixAdminViewModelField {
+            field_name: column.name.to_string(),
+            html_input_type: String::new(),
+            select_list: String::new(),
+            is_option: true,
+            field_type: column.field_type.clone(),
+            list_sort_position: 99,
+            list_hide_column: false,
+            list_regex_mask: None,
+            label: column.label.clone(),
+            help_text: None,
+            placeholder: None,
+            readonly_after_create: false,
+            form_hidden: true,
+            is_computed: true,
+            is_sortable: column.is_sortable(),
+            renderer: None,
+            number_input: None,
+        }
+    }
+}
+
+pub trait ActixAdminComputedColumnTrait<E: EntityTrait> {
+    fn get_computed_columns() -> Vec<ActixAdminComputedColumn<E>> {
+        Vec::new()
+    }
+}
+
+/// Orders the query by the SQL expression of the computed column named sort_by and the primary key as tiebreaker.
+/// Returns None if there is no such column.
+pub fn apply_sort_by_computed_column<E: EntityTrait>(
+    query: Select<E>,
+    columns: &[ActixAdminComputedColumn<E>],
+    sort_by: &str,
+    primary_key_column: E::Column,
+    sort_order: &SortOrder,
+) -> Option<Select<E>> {
+    let expr = columns.iter().find_map(|column| match &column.value {
+        ActixAdminComputedValue::Expr(expr) if column.name == sort_by => Some(expr.clone()),
+        _ => None,
+    })?;
+    let order = match sort_order {
+        SortOrder::Asc => Order::Asc,
+        SortOrder::Desc => Order::Desc,
+    };
+
+    Some(
+        query
+            .order_by(Expr::expr(expr), order.clone())
+            .order_by(primary_key_column, order),
+    )
+}
+
+fn get_computed_value(row: &QueryResult, alias: &str) -> String {
+    if let Ok(value) = row.try_get::<Option<String>>("", alias) {
+        return value.unwrap_or_default();
+    }
+    if let Ok(value) = row.try_get::<Option<i64>>("", alias) {
+        return value.map(|v| v.to_string()).unwrap_or_default();
+    }
+    if let Ok(value) = row.try_get::<Option<f64>>("", alias) {
+        return value.map(|v| v.to_string()).unwrap_or_default();
+    }
+    if let Ok(value) = row.try_get::<Option<bool>>("", alias) {
+        return value.map(|v| v.to_string()).unwrap_or_default();
+    }
+
+    String::new()
+}
+
+/// Fetches the entities of the query together with the values of the computed columns
+pub async fn find_with_computed_columns<E>(
+    db: &DatabaseConnection,
+    query: Select<E>,
+    columns: &[ActixAdminComputedColumn<E>],
+) -> Result<Vec<ActixAdminModel>, ActixAdminError>
+where
+    E: EntityTrait,
+    E::Model: Into<ActixAdminModel>,
+{
+    let query = columns.iter().fold(query, |query, column| match &column.value {
+        ActixAdminComputedValue::Expr(expr) => query.column_as(expr.clone(), column.get_alias().as_str()),
+        ActixAdminComputedValue::Fn(_) => query,
+    });
+    let rows = db.query_all(query.build(db.get_database_backend())).await?;
+
+    let mut models = Vec::new();
+    for row in rows {
+        let entity = E::Model::from_query_result(&row, "")?;
+
+        let mut computed_values = HashMap::new();
+        for column in columns {
+            let value = match &column.value {
+                ActixAdminComputedValue::Expr(_) => {
+                    get_computed_value(&row, &column.get_alias())
+                }
+                ActixAdminComputedValue::Fn(compute) => compute(db, &entity).await?,
+            };
+            computed_values.insert(column.name.to_string(), value);
+        }
+
+        let mut model: ActixAdminModel = entity.into();
+        model.values.extend(computed_values);
+        models.push(model);
+    }
+
+    Ok(models)
+}
@@ -0,0 +1,39 @@
+use async_trait::async_trait;
+use sea_orm::DatabaseTransaction;
+
+use crate::{ActixAdminError, ActixAdminModel};
+
+/// Hooks which are called around the writes of the create, edit and delete views.
+///
+/// The hooks run in the transaction of the write, so their queries are committed together with it.
+/// An error aborts the write and rolls back the transaction, [ActixAdminError::HookError] shows its
+/// message to the user.
+#[async_trait(?Send)]
+pub trait ActixAdminModelHooksTrait {
+    /// Called before the entity is inserted, changed values of the model are saved
+    async fn before_create(_txn: &DatabaseTransaction, _model: &mut ActixAdminModel) -> Result<(), ActixAdminError> {
+        Ok(())
+    }
+
+    /// Called after the entity is inserted, the model contains the primary key of the new entity
+    async fn after_create(_txn: &DatabaseTransaction, _model: &ActixAdminModel) -> Result<(), ActixAdminError> {
+        Ok(())
+    }
+
+    /// Called before the entity with the id is updated, changed values of the model are saved
+    async fn before_update(_txn: &DatabaseTransaction, _id: i32, _model: &mut ActixAdminModel) -> Result<(), ActixAdminError> {
+        Ok(())
+    }
+
+    async fn after_update(_txn: &DatabaseTransaction, _id: i32, _model: &ActixAdminModel) -> Result<(), ActixAdminError> {
+        Ok(())
+    }
+
+    async fn before_delete(_txn: &DatabaseTransaction, _id: i32) -> Result<(), ActixAdminError> {
+        Ok(())
+    }
+
+    async fn after_delete(_txn: &DatabaseTransaction, _id: i32) -> Result<(), ActixAdminError> {
+        Ok(())
+    }
+}
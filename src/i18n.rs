@@ -0,0 +1,221 @@
+use actix_session::Session;
+use actix_web::http::header::ACCEPT_LANGUAGE;
+use actix_web::HttpRequest;
+use chrono::{NaiveDate, NaiveDateTime};
+use serde_derive::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tera::{to_value, try_get_value, Tera};
+
+use crate::view_model::ActixAdminViewModelFieldType;
+use crate::ActixAdmin;
+
+/// Session key holding the locale chosen by the user
+pub const LOCALE_SESSION_KEY: &str = "actix_admin_locale";
+
+/// Message catalogue and formats of a locale.
+///
+/// Messages are keyed by the english text of the built-in strings. Entity and field labels
+/// use the keys `entity.<entity_name>` and `field.<entity_name>.<field_name>` or `field.<field_name>`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ActixAdminLocale {
+    pub name: String,
+    pub date_format: String,
+    pub datetime_format: String,
+    pub decimal_separator: String,
+    pub thousands_separator: String,
+    #[serde(default)]
+    pub messages: HashMap<String, String>,
+}
+
+impl ActixAdminLocale {
+    pub fn from_json(json: &str) -> Result<ActixAdminLocale, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Returns the translation of the message or the message itself if there is none
+    pub fn translate<'a>(&'a self, message: &'a str) -> &'a str {
+        self.messages.get(message).map(|m| m.as_str()).unwrap_or(message)
+    }
+
+    pub fn get_entity_label(&self, entity_name: &str) -> String {
+        match self.messages.get(&format!("entity.{}", entity_name)) {
+            Some(label) => label.to_string(),
+            None => to_title(entity_name),
+        }
+    }
+
+    /// Returns the translated label of a field. Without translation the label of the
+    /// `#[actix_admin(label = "...")]` attribute or the title-cased field name is used.
+    pub fn get_field_label(&self, entity_name: &str, field_name: &str, label: Option<&str>) -> String {
+        self.messages
+            .get(&format!("field.{}.{}", entity_name, field_name))
+            .or_else(|| self.messages.get(&format!("field.{}", field_name)))
+            .map(|label| label.to_string())
+            .or_else(|| label.map(|label| self.translate(label).to_string()))
+            .unwrap_or_else(|| to_title(field_name))
+    }
+
+    /// Formats dates, date times and numbers of the list and show views, other values are returned unchanged
+    pub fn format_value(&self, value: &str, field_type: &ActixAdminViewModelFieldType) -> String {
+        match field_type {
+            ActixAdminViewModelFieldType::Date => NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .map(|date| date.format(&self.date_format).to_string())
+                .unwrap_or_else(|_| value.to_string()),
+            // datetimes with offset are converted to the timezone of the user before they are rendered
+            ActixAdminViewModelFieldType::DateTime | ActixAdminViewModelFieldType::DateTimeWithTimeZone => NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S%.f")
+                .map(|datetime| datetime.format(&self.datetime_format).to_string())
+                .unwrap_or_else(|_| value.to_string()),
+            ActixAdminViewModelFieldType::Number if value.parse::<f64>().is_ok() => self.format_number(value),
+            _ => value.to_string(),
+        }
+    }
+
+    fn format_number(&self, value: &str) -> String {
+        let (sign, digits) = match value.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", value),
+        };
+        let (integer, fraction) = match digits.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (digits, None),
+        };
+
+        let mut grouped = String::new();
+        for (i, c) in integer.chars().enumerate() {
+            if i > 0 && (integer.len() - i) % 3 == 0 {
+                grouped.push_str(&self.thousands_separator);
+            }
+            grouped.push(c);
+        }
+
+        match fraction {
+            Some(fraction) => format!("{}{}{}{}", sign, grouped, self.decimal_separator, fraction),
+            None => format!("{}{}", sign, grouped),
+        }
+    }
+}
+
+fn to_title(name: &str) -> String {
+    // foreign keys are labeled by the entity they reference, e.g. `post_id` as Post
+    let name = name.strip_suffix("_id").unwrap_or(name);
+    name.split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().collect::<String>() + &chars.as_str().to_lowercase(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// Returns the locales shipped with actix-admin
+pub fn get_builtin_locales() -> HashMap<String, ActixAdminLocale> {
+    let mut locales = HashMap::new();
+    locales.insert("en".to_string(), ActixAdminLocale::from_json(include_str!("locales/en.json")).unwrap());
+    locales.insert("de".to_string(), ActixAdminLocale::from_json(include_str!("locales/de.json")).unwrap());
+    locales
+}
+
+/// Chooses the locale of a request: the locale stored in the session, the best match of the
+/// Accept-Language header or the default locale of the configuration
+pub fn get_locale(req: Option<&HttpRequest>, session: &Session, actix_admin: &ActixAdmin) -> String {
+    let session_locale = session
+        .get::<String>(LOCALE_SESSION_KEY)
+        .unwrap_or(None)
+        .filter(|locale| actix_admin.locales.contains_key(locale));
+    if let Some(locale) = session_locale {
+        return locale;
+    }
+
+    req.and_then(|req| req.headers().get(ACCEPT_LANGUAGE))
+        .and_then(|header| header.to_str().ok())
+        .and_then(|header| get_accepted_locale(header, actix_admin))
+        .unwrap_or_else(|| actix_admin.configuration.default_locale.to_string())
+}
+
+fn get_accepted_locale(accept_language: &str, actix_admin: &ActixAdmin) -> Option<String> {
+    let mut languages: Vec<(String, f32)> = accept_language
+        .split(',')
+        .filter_map(|language| {
+            let mut parts = language.trim().split(';');
+            let tag = parts.next()?.trim().to_lowercase();
+            let quality = parts
+                .find_map(|part| part.trim().strip_prefix("q="))
+                .and_then(|q| q.parse::<f32>().ok())
+                .unwrap_or(1.0);
+            (!tag.is_empty()).then_some((tag, quality))
+        })
+        .collect();
+    languages.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    languages.into_iter().find_map(|(tag, _)| {
+        let primary_tag = tag.split('-').next().unwrap_or_default().to_string();
+        [tag, primary_tag]
+            .into_iter()
+            .find(|locale| actix_admin.locales.contains_key(locale))
+    })
+}
+
+fn get_locale_arg<'a>(
+    locales: &'a HashMap<String, ActixAdminLocale>,
+    args: &HashMap<String, tera::Value>,
+) -> Option<&'a ActixAdminLocale> {
+    args.get("locale")
+        .and_then(|locale| locale.as_str())
+        .and_then(|locale| locales.get(locale))
+}
+
+/// Registers the filters `t`, `entity_label`, `field_label` and `localize`, which all take the locale as argument.
+/// `field_label` additionally takes the entity name and the label of the field attribute.
+pub fn register_tera_filters(tera: &mut Tera, locales: Arc<HashMap<String, ActixAdminLocale>>) {
+    let t_locales = locales.clone();
+    tera.register_filter("t", move |value: &tera::Value, args: &HashMap<String, tera::Value>| {
+        let message = try_get_value!("t", "value", String, value);
+        let translation = match get_locale_arg(&t_locales, args) {
+            Some(locale) => locale.translate(&message).to_string(),
+            None => message,
+        };
+        Ok(to_value(translation).unwrap())
+    });
+
+    let entity_label_locales = locales.clone();
+    tera.register_filter("entity_label", move |value: &tera::Value, args: &HashMap<String, tera::Value>| {
+        let entity_name = try_get_value!("entity_label", "value", String, value);
+        let label = match get_locale_arg(&entity_label_locales, args) {
+            Some(locale) => locale.get_entity_label(&entity_name),
+            None => to_title(&entity_name),
+        };
+        Ok(to_value(label).unwrap())
+    });
+
+    let field_label_locales = locales.clone();
+    tera.register_filter("field_label", move |value: &tera::Value, args: &HashMap<String, tera::Value>| {
+        let field_name = try_get_value!("field_label", "value", String, value);
+        let entity_name = args.get("entity").and_then(|e| e.as_str()).unwrap_or_default();
+        let attr_label = args.get("label").and_then(|l| l.as_str());
+        let label = match get_locale_arg(&field_label_locales, args) {
+            Some(locale) => locale.get_field_label(entity_name, &field_name, attr_label),
+            None => attr_label.map(|l| l.to_string()).unwrap_or_else(|| to_title(&field_name)),
+        };
+        Ok(to_value(label).unwrap())
+    });
+
+    tera.register_filter("localize", move |value: &tera::Value, args: &HashMap<String, tera::Value>| {
+        let value = match value {
+            tera::Value::String(s) => s.to_string(),
+            other => other.to_string(),
+        };
+        let field_type = args
+            .get("field_type")
+            .and_then(|field_type| serde_json::from_value::<ActixAdminViewModelFieldType>(field_type.clone()).ok());
+        let formatted = match (get_locale_arg(&locales, args), field_type) {
+            (Some(locale), Some(field_type)) => locale.format_value(&value, &field_type),
+            _ => value,
+        };
+        Ok(to_value(formatted).unwrap())
+    });
+}
@@ -16,21 +16,47 @@ use sea_orm::DatabaseConnection;
 use serde_derive::Serialize;
 use tera::Tera;
 use std::collections::HashMap;
+use std::sync::Arc;
 
+pub mod assets;
 pub mod builder;
+pub mod computed;
+pub mod hooks;
+pub mod i18n;
+pub mod locking;
 pub mod model;
+pub mod pagination;
+pub mod renderer;
 pub mod routes;
+pub mod saved_view;
+pub mod search;
+pub mod theme;
+pub mod timezone;
 pub mod view_model;
 
 pub mod prelude {
+    pub use crate::assets::ActixAdminAssetUrls;
     pub use crate::builder::{ActixAdminBuilder, ActixAdminBuilderTrait};
-    pub use crate::model::{ActixAdminModel, ActixAdminModelTrait, ActixAdminModelValidationTrait, ActixAdminModelFilter, ActixAdminModelFilterTrait, ActixAdminModelFilterType};
+    pub use crate::computed::{
+        ActixAdminComputedColumn, ActixAdminComputedColumnTrait, ActixAdminComputedFn, ActixAdminComputedFuture, ActixAdminComputedValue,
+        apply_sort_by_computed_column, find_with_computed_columns
+    };
+    pub use crate::hooks::ActixAdminModelHooksTrait;
+    pub use crate::i18n::{ActixAdminLocale, LOCALE_SESSION_KEY};
+    pub use crate::locking::{ActixAdminConflict, ActixAdminConflictChange, ActixAdminVersionTrait};
+    pub use crate::model::{ActixAdminModel, ActixAdminModelTrait, ActixAdminModelValidationTrait, ActixAdminValidationErrors, ActixAdminValidationMode, ActixAdminModelFilter, ActixAdminModelFilterTrait, ActixAdminModelFilterType, ActixAdminModelRangeFilter};
+    pub use crate::pagination::{ActixAdminCountCache, ActixAdminCountType, ActixAdminCursor, ActixAdminPaginationType, apply_sort_and_cursor};
+    pub use crate::renderer::{ActixAdminRenderer, ActixAdminRendererFn};
     pub use crate::routes::{create_or_edit_post, get_admin_ctx, SortOrder};
+    pub use crate::saved_view::{ActixAdminSavedView, ActixAdminSavedViewStoreTrait, SeaOrmSavedViewStore};
+    pub use crate::theme::{ActixAdminEnvironment, ActixAdminTheme, ActixAdminThemeMode};
+    pub use crate::timezone::TIMEZONE_SESSION_KEY;
+    pub use crate::search::{ActixAdminSearchFn, ActixAdminSearchOperator, ActixAdminSearchTerm, ActixAdminSearchValue, get_search_term_condition, parse_search_terms};
     pub use crate::view_model::{
-        ActixAdminViewModel, ActixAdminViewModelField, ActixAdminViewModelFieldType,
+        ActixAdminViewModel, ActixAdminNumberInput, ActixAdminViewModelField, ActixAdminViewModelFieldType,
         ActixAdminViewModelSerializable, ActixAdminViewModelTrait, ActixAdminViewModelFilter
     };
-    pub use crate::{hashmap, ActixAdminSelectListTrait};
+    pub use crate::{hashmap, ActixAdminSelectListKeyTrait, ActixAdminSelectListTrait};
     pub use crate::{ActixAdmin, ActixAdminConfiguration, ActixAdminError};
     pub use actix_admin_macros::{
         DeriveActixAdmin, DeriveActixAdminEnumSelectList, DeriveActixAdminModel,
@@ -38,8 +64,10 @@ pub mod prelude {
     };
     pub use actix_session::Session;
     pub use async_trait::async_trait;
+    pub use chrono_tz::Tz;
     pub use itertools::izip;
     pub use lazy_static::lazy_static;
+    pub use sea_orm::DatabaseTransaction;
 }
 
 use crate::prelude::*;
@@ -53,6 +81,24 @@ macro_rules! hashmap {
     }}
 }
 
+/// Converts the values of select list fields from and to the keys of the options. Types implementing
+/// FromStr and Display use them, enums deriving DeriveActixAdminEnumSelectList and DeriveActiveEnum
+/// have inherent functions of the same name which use the database values instead.
+pub trait ActixAdminSelectListKeyTrait: Sized {
+    fn from_select_list_key(key: &str) -> Option<Self>;
+    fn to_select_list_key(&self) -> String;
+}
+
+impl<T: std::str::FromStr + ToString> ActixAdminSelectListKeyTrait for T {
+    fn from_select_list_key(key: &str) -> Option<Self> {
+        key.parse().ok()
+    }
+
+    fn to_select_list_key(&self) -> String {
+        self.to_string().trim_start_matches('\'').trim_end_matches('\'').to_string()
+    }
+}
+
 // SelectListTrait
 #[async_trait]
 pub trait ActixAdminSelectListTrait {
@@ -65,10 +111,17 @@ pub trait ActixAdminSelectListTrait {
 pub struct ActixAdminConfiguration {
     pub enable_auth: bool,
     pub user_is_logged_in: Option<for<'a> fn(&'a Session) -> bool>,
+    pub user_identifier: Option<for<'a> fn(&'a Session) -> Option<String>>,
     pub login_link: Option<String>,
     pub logout_link: Option<String>,
     pub file_upload_directory: &'static str,
-    pub navbar_title: &'static str
+    pub navbar_title: &'static str,
+    pub default_locale: &'static str,
+    /// Timezone in which datetimes with offset are shown and entered, unless the user chose another one
+    pub default_timezone: Tz,
+    pub asset_urls: Option<ActixAdminAssetUrls>,
+    pub theme: ActixAdminTheme,
+    pub debug_mode: bool
 }
 
 #[derive(Clone)]
@@ -76,7 +129,12 @@ pub struct ActixAdmin {
     pub entity_names: HashMap<String, Vec<ActixAdminMenuElement>>,
     pub view_models: HashMap<String, ActixAdminViewModel>,
     pub configuration: ActixAdminConfiguration,
-    pub tera: Tera
+    pub tera: Tera,
+    pub saved_view_store: Option<Arc<dyn ActixAdminSavedViewStoreTrait>>,
+    pub count_cache: ActixAdminCountCache,
+    pub search_functions: HashMap<String, ActixAdminSearchFn>,
+    pub locales: HashMap<String, ActixAdminLocale>,
+    pub renderers: HashMap<String, ActixAdminRenderer>
 }
 
 #[derive(PartialEq, Eq, Clone, Serialize)]
@@ -108,31 +166,67 @@ pub enum ActixAdminError {
     EditError,
 
     #[display(fmt = "Database error")]
-    DatabaseError,
+    DatabaseError(#[error(source)] sea_orm::DbErr),
 
     #[display(fmt = "Entity does not exist")]
     EntityDoesNotExistError,
+
+    /// Aborts a write from a hook with a message for the user
+    #[display(fmt = "{}", _0)]
+    HookError(#[error(not(source))] String),
+
+    /// A submitted id which is not a valid primary key
+    #[display(fmt = "Invalid id {}", _0)]
+    InvalidIdError(#[error(not(source))] String),
+
+    /// Errors of the ids which prevented a bulk delete, nothing was deleted
+    #[display(fmt = "Could not delete entities")]
+    DeleteManyError(#[error(not(source))] Vec<(i32, ActixAdminError)>),
+
+    /// The entity was changed since the form was loaded, see `#[actix_admin(version)]`
+    #[display(fmt = "Entity was changed in the meantime")]
+    ConflictError(#[error(not(source))] locking::ActixAdminConflict),
+}
+
+impl ActixAdminError {
+    /// Returns the message of the error followed by the messages of its sources
+    pub fn get_error_chain(&self) -> Vec<String> {
+        let mut chain = vec![self.to_string()];
+        let mut source = std::error::Error::source(self);
+        while let Some(err) = source {
+            chain.push(err.to_string());
+            source = err.source();
+        }
+        chain
+    }
 }
 
 impl error::ResponseError for ActixAdminError {
     fn error_response(&self) -> HttpResponse {
         #[cfg(feature="enable-tracing")]
-        tracing::debug!("{self:#?}");
+        tracing::error!("{}", self.get_error_chain().join(": "));
         HttpResponse::build(self.status_code())
             .insert_header(ContentType::html())
-            .body(self.to_string())
+            .body(tera::escape_html(&self.to_string()))
     }
 
     fn status_code(&self) -> StatusCode {
         match *self {
+            ActixAdminError::EntityDoesNotExistError => StatusCode::NOT_FOUND,
+            ActixAdminError::InvalidIdError(_) => StatusCode::BAD_REQUEST,
+            ActixAdminError::ValidationErrors | ActixAdminError::HookError(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            ActixAdminError::ConflictError(_) => StatusCode::CONFLICT,
+            ActixAdminError::DeleteManyError(ref failures) => failures
+                .first()
+                .map_or(StatusCode::UNPROCESSABLE_ENTITY, |(_, err)| err.status_code()),
             _ => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
 }
 
 impl std::convert::From<sea_orm::DbErr> for ActixAdminError {
-    fn from(_err: sea_orm::DbErr) -> ActixAdminError {
-        ActixAdminError::DatabaseError
+    fn from(err: sea_orm::DbErr) -> ActixAdminError {
+        ActixAdminError::DatabaseError(err)
     }
 }
 
@@ -141,6 +235,8 @@ impl std::convert::From<sea_orm::DbErr> for ActixAdminError {
 pub enum ActixAdminNotificationType {
     #[display(fmt = "is-danger")]
     Danger,
+    #[display(fmt = "is-warning")]
+    Warning,
 }
 
 #[derive(Debug, Serialize)]
@@ -149,6 +245,15 @@ pub struct ActixAdminNotification {
     message: String,
 }
 
+impl ActixAdminNotification {
+    pub fn new(notification_type: ActixAdminNotificationType, message: String) -> ActixAdminNotification {
+        ActixAdminNotification {
+            css_class: notification_type.to_string(),
+            message,
+        }
+    }
+}
+
 impl std::convert::From<ActixAdminError> for ActixAdminNotification {
     fn from(e: ActixAdminError) -> ActixAdminNotification {
         ActixAdminNotification {
@@ -0,0 +1,126 @@
+use chrono::{DateTime, Duration, FixedOffset, NaiveDateTime, SubsecRound, Utc};
+use serde_derive::Serialize;
+
+use crate::timezone::{parse_datetime_with_timezone, parse_naive_datetime};
+use crate::view_model::ActixAdminViewModelField;
+use crate::ActixAdminModel;
+
+/// Values of a column for optimistic locking, which is declared with `#[actix_admin(version)]`
+pub trait ActixAdminVersionTrait: Sized {
+    /// Version of a created entity
+    fn initial_version() -> Self;
+    /// Version of the entity after an update, which differs from the current version
+    fn next_version(&self) -> Self;
+    /// Parses the version which was loaded with the form
+    fn parse_version(value: &str) -> Option<Self>;
+}
+
+macro_rules! impl_integer_version {
+    ($($ty:ty),*) => {
+        $(
+            impl ActixAdminVersionTrait for $ty {
+                fn initial_version() -> Self {
+                    1
+                }
+
+                fn next_version(&self) -> Self {
+                    self.wrapping_add(1)
+                }
+
+                fn parse_version(value: &str) -> Option<Self> {
+                    value.parse().ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_version!(i16, i32, i64, u16, u32, u64);
+
+// timestamps are truncated to microseconds, the precision of most databases
+fn now() -> DateTime<Utc> {
+    Utc::now().trunc_subsecs(6)
+}
+
+impl ActixAdminVersionTrait for NaiveDateTime {
+    fn initial_version() -> Self {
+        now().naive_utc()
+    }
+
+    fn next_version(&self) -> Self {
+        std::cmp::max(now().naive_utc(), *self + Duration::microseconds(1))
+    }
+
+    fn parse_version(value: &str) -> Option<Self> {
+        parse_naive_datetime(value)
+    }
+}
+
+impl ActixAdminVersionTrait for DateTime<Utc> {
+    fn initial_version() -> Self {
+        now()
+    }
+
+    fn next_version(&self) -> Self {
+        std::cmp::max(now(), *self + Duration::microseconds(1))
+    }
+
+    fn parse_version(value: &str) -> Option<Self> {
+        parse_datetime_with_timezone(value).map(|datetime| datetime.with_timezone(&Utc))
+    }
+}
+
+impl ActixAdminVersionTrait for DateTime<FixedOffset> {
+    fn initial_version() -> Self {
+        now().into()
+    }
+
+    fn next_version(&self) -> Self {
+        std::cmp::max(now().into(), *self + Duration::microseconds(1))
+    }
+
+    fn parse_version(value: &str) -> Option<Self> {
+        parse_datetime_with_timezone(value)
+    }
+}
+
+/// An edit which was rejected because the entity was changed since the form was loaded
+#[derive(Clone, Debug, Serialize)]
+pub struct ActixAdminConflict {
+    /// Current version of the entity, submitting the form again with it overwrites the concurrent change
+    pub version: String,
+    pub changes: Vec<ActixAdminConflictChange>,
+}
+
+/// A field of the form whose submitted value differs from its current value
+#[derive(Clone, Debug, Serialize)]
+pub struct ActixAdminConflictChange {
+    pub field_name: String,
+    pub label: Option<String>,
+    pub submitted_value: String,
+    pub current_value: String,
+}
+
+impl ActixAdminConflict {
+    pub fn new(current: &ActixAdminModel, submitted: &ActixAdminModel, fields: &[ActixAdminViewModelField], version_field: &str) -> Self {
+        let changes = fields
+            .iter()
+            .filter(|field| !field.form_hidden)
+            .filter_map(|field| {
+                let current_value = current.values.get(&field.field_name).cloned().unwrap_or_default();
+                let submitted_value = submitted.values.get(&field.field_name).cloned().unwrap_or_default();
+                (current_value != submitted_value).then(|| ActixAdminConflictChange {
+                    field_name: field.field_name.to_string(),
+                    label: field.label.clone(),
+                    submitted_value,
+                    current_value,
+                })
+            })
+            .collect();
+
+        ActixAdminConflict {
+            version: current.values.get(version_field).cloned().unwrap_or_default(),
+            changes,
+        }
+    }
+}
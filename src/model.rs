@@ -1,13 +1,16 @@
+use crate::pagination::ActixAdminCursor;
 use crate::routes::SortOrder;
 use crate::view_model::ActixAdminViewModelFilter;
+use crate::timezone::{parse_datetime_with_timezone, parse_naive_datetime};
 use crate::{ActixAdminError, ActixAdminViewModelField};
 use actix_multipart::{Multipart, MultipartError};
+use actix_session::Session;
 use actix_web::web::Bytes;
 use async_trait::async_trait;
-use chrono::{NaiveDate, NaiveDateTime};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
 use futures_util::stream::StreamExt as _;
 use sea_orm::{DatabaseConnection, EntityTrait};
-use serde_derive::Serialize;
+use serde_derive::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::Write;
@@ -15,23 +18,63 @@ use std::time::{SystemTime, UNIX_EPOCH};
 
 #[async_trait]
 pub trait ActixAdminModelTrait {
+    /// Returns the entities of the page or cursor and whether more entities follow in the direction of the query
+    #[allow(clippy::too_many_arguments)]
     async fn list_model(
         db: &DatabaseConnection,
         page: u64,
         posts_per_page: u64,
+        cursor: Option<ActixAdminCursor>,
         filter_values: HashMap<String, Option<String>>,
         search: &str,
         sort_by: &str,
         sort_order: &SortOrder
-    ) -> Result<(u64, Vec<ActixAdminModel>), ActixAdminError>;
+    ) -> Result<(Vec<ActixAdminModel>, bool), ActixAdminError>;
+    async fn count_model(
+        db: &DatabaseConnection,
+        filter_values: HashMap<String, Option<String>>,
+        search: &str
+    ) -> Result<u64, ActixAdminError>;
     fn get_fields() -> &'static [ActixAdminViewModelField];
     fn validate_model(model: &mut ActixAdminModel);
 }
 
+/// Whether an entity is validated before it is created or before the entity with the id is edited
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ActixAdminValidationMode {
+    Create,
+    Edit(i32),
+}
+
+/// Errors of a validation by field name and errors which concern the whole form
+#[derive(Clone, Debug, Default)]
+pub struct ActixAdminValidationErrors {
+    pub field_errors: HashMap<String, String>,
+    pub form_errors: Vec<String>,
+}
+
+#[async_trait(?Send)]
 pub trait ActixAdminModelValidationTrait<T> {
     fn validate(_model: &T) -> HashMap<String, String> {
         return HashMap::new();
     }
+
+    /// Validates values which depend on each other, e.g. an end date which must be after the start date.
+    /// The errors concern the whole form and are shown above the fields.
+    fn validate_form(_model: &T) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Validates the model with access to the database and the session, e.g. to check that a value is unique.
+    /// It is only called if the values of the fields, [validate](Self::validate) and [validate_form](Self::validate_form) have no errors.
+    async fn validate_async(
+        _model: &T,
+        _db: &DatabaseConnection,
+        _session: &Session,
+        _mode: &ActixAdminValidationMode
+    ) -> Result<ActixAdminValidationErrors, ActixAdminError> {
+        Ok(ActixAdminValidationErrors::default())
+    }
 }
 
 pub struct ActixAdminModelFilter<E: EntityTrait> {
@@ -41,13 +84,26 @@ pub struct ActixAdminModelFilter<E: EntityTrait> {
     pub values: Option<Vec<(String, String)>>
 }
 
+/// A filter with a from and a to input, the bounds are passed to the filter function as entered and
+/// are `None` if they are empty
+pub struct ActixAdminModelRangeFilter<E: EntityTrait> {
+    pub name: String,
+    /// One of the range types `NumberRange`, `IntegerRange`, `DateRange` or `DateTimeRange`
+    pub filter_type: ActixAdminModelFilterType,
+    pub filter: fn(sea_orm::Select<E>, Option<String>, Option<String>) -> sea_orm::Select<E>
+}
+
 #[derive(Clone, Debug, Serialize)]
 pub enum ActixAdminModelFilterType {
     Text,
     SelectList,
     Date,
     DateTime,
-    Checkbox
+    Checkbox,
+    NumberRange,
+    IntegerRange,
+    DateRange,
+    DateTimeRange
 }
 
 #[async_trait]
@@ -55,6 +111,9 @@ pub trait ActixAdminModelFilterTrait<E: EntityTrait> {
     fn get_filter() -> Vec<ActixAdminModelFilter<E>> {
         Vec::new()
     }
+    fn get_range_filter() -> Vec<ActixAdminModelRangeFilter<E>> {
+        Vec::new()
+    }
     async fn get_filter_values(_filter: &ActixAdminModelFilter<E>, _db: &DatabaseConnection)-> Option<Vec<(String, String)>> {
         None
     }
@@ -65,18 +124,34 @@ impl<T: EntityTrait> From<ActixAdminModelFilter<T>> for ActixAdminViewModelFilte
         ActixAdminViewModelFilter {
             name: filter.name,
             value: None,
+            value_to: None,
             values: None,
             filter_type: Some(filter.filter_type)
         }
     }
 }
 
-#[derive(Clone, Debug, Serialize)]
+impl<T: EntityTrait> From<ActixAdminModelRangeFilter<T>> for ActixAdminViewModelFilter {
+    fn from(filter: ActixAdminModelRangeFilter<T>) -> Self {
+        ActixAdminViewModelFilter {
+            name: filter.name,
+            value: None,
+            value_to: None,
+            values: None,
+            filter_type: Some(filter.filter_type)
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ActixAdminModel {
     pub primary_key: Option<String>,
     pub values: HashMap<String, String>,
     pub errors: HashMap<String, String>,
     pub custom_errors: HashMap<String, String>,
+    /// Errors which do not belong to a single field
+    #[serde(default)]
+    pub form_errors: Vec<String>,
 }
 
 impl ActixAdminModel {
@@ -86,6 +161,7 @@ impl ActixAdminModel {
             values: HashMap::new(),
             errors: HashMap::new(),
             custom_errors: HashMap::new(),
+            form_errors: Vec::new(),
         }
     }
 
@@ -139,6 +215,7 @@ impl ActixAdminModel {
             values: hashmap,
             errors: HashMap::new(),
             custom_errors: HashMap::new(),
+            form_errors: Vec::new(),
         })
     }
 
@@ -157,9 +234,18 @@ impl ActixAdminModel {
         is_option_or_string: bool,
         is_allowed_to_be_empty: bool
     ) -> Result<Option<NaiveDateTime>, String> {
-        self.get_value_by_closure(key, is_option_or_string, is_allowed_to_be_empty, |val| {
-            NaiveDateTime::parse_from_str(val, "%Y-%m-%dT%H:%M")
-        })
+        self.get_value_by(key, is_option_or_string, is_allowed_to_be_empty, parse_naive_datetime)
+    }
+
+    /// Parses a datetime with offset, the local datetimes of the form are converted by
+    /// [delocalize_model](crate::timezone::delocalize_model) before
+    pub fn get_datetime_with_timezone(
+        &self,
+        key: &str,
+        is_option_or_string: bool,
+        is_allowed_to_be_empty: bool
+    ) -> Result<Option<DateTime<FixedOffset>>, String> {
+        self.get_value_by(key, is_option_or_string, is_allowed_to_be_empty, parse_datetime_with_timezone)
     }
 
     pub fn get_date(
@@ -173,8 +259,35 @@ impl ActixAdminModel {
         })
     }
 
+    /// Parses the value of a time input, which contains seconds only if they are not zero
+    pub fn get_time(
+        &self,
+        key: &str,
+        is_option_or_string: bool,
+        is_allowed_to_be_empty: bool
+    ) -> Result<Option<NaiveTime>, String> {
+        self.get_value_by_closure(key, is_option_or_string, is_allowed_to_be_empty, |val| {
+            NaiveTime::parse_from_str(val, "%H:%M:%S%.f").or_else(|_| NaiveTime::parse_from_str(val, "%H:%M"))
+        })
+    }
+
+    /// Parses the value as JSON, the error of an invalid value contains the position of the syntax error
+    pub fn get_json(
+        &self,
+        key: &str,
+        is_option_or_string: bool,
+        is_allowed_to_be_empty: bool
+    ) -> Result<Option<serde_json::Value>, String> {
+        match self.values.get(key) {
+            Some(val) if !val.trim().is_empty() => serde_json::from_str(val)
+                .map(Some)
+                .map_err(|err| format!("Invalid JSON: {}", err)),
+            _ => self.get_value_by_closure(key, is_option_or_string, is_allowed_to_be_empty, |val| val.parse()),
+        }
+    }
+
     pub fn get_bool(&self, key: &str, is_option_or_string: bool, is_allowed_to_be_empty: bool) -> Result<Option<bool>, String> {
-        let val = self.get_value_by_closure(key, is_option_or_string, is_allowed_to_be_empty ,|val| {
+        let val = self.get_value_by_closure(key, is_option_or_string, is_allowed_to_be_empty ,|val| -> Result<bool, ()> {
             if !val.is_empty() && (val == "true" || val == "yes") {
                 Ok(true)
             } else {
@@ -188,12 +301,23 @@ impl ActixAdminModel {
         }
     }
 
-    fn get_value_by_closure<T: std::str::FromStr>(
+    /// Parses the value with a function returning None for invalid values, e.g. the key of a select list option
+    pub fn get_value_by<T>(
+        &self,
+        key: &str,
+        is_option_or_string: bool,
+        is_allowed_to_be_empty: bool,
+        parse: impl Fn(&str) -> Option<T>,
+    ) -> Result<Option<T>, String> {
+        self.get_value_by_closure(key, is_option_or_string, is_allowed_to_be_empty, |val| parse(val).ok_or(()))
+    }
+
+    fn get_value_by_closure<T, E>(
         &self,
         key: &str,
         is_option_or_string: bool,
         is_allowed_to_be_empty: bool,
-        f: impl Fn(&String) -> Result<T, <T as std::str::FromStr>::Err>,
+        f: impl Fn(&String) -> Result<T, E>,
     ) -> Result<Option<T>, String> {
         let value = self.values.get(key);
 
@@ -225,6 +349,6 @@ impl ActixAdminModel {
     }
 
     pub fn has_errors(&self) -> bool {
-        return (&self.errors.len() + &self.custom_errors.len()) != 0 as usize;
+        !self.errors.is_empty() || !self.custom_errors.is_empty() || !self.form_errors.is_empty()
     }
 }
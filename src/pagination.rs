@@ -0,0 +1,208 @@
+use sea_orm::sea_query::{Alias, Expr, Order, Query, SelectStatement, SimpleExpr, SubQueryStatement};
+use sea_orm::{
+    Condition, ConnectionTrait, DatabaseBackend, DatabaseConnection, EntityTrait, IntoSimpleExpr,
+    QueryFilter, QueryOrder, Select, Statement,
+};
+use serde_derive::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use urlencoding::encode;
+
+use crate::routes::SortOrder;
+use crate::view_model::ActixAdminViewModelFilter;
+use crate::ActixAdminError;
+
+/// How the list view of an entity is paginated
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ActixAdminPaginationType {
+    /// Numbered pages, queried with LIMIT and OFFSET
+    Offset,
+    /// Next and previous links, queried relative to the first or last row of the current page
+    Keyset,
+}
+
+/// How the total number of entities is determined for the list view
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ActixAdminCountType {
+    /// Runs a COUNT query on every request
+    Exact,
+    /// Uses the table statistics of the database, only available for unfiltered lists
+    Estimated,
+    /// Runs a COUNT query and reuses its result for the given duration
+    Cached(Duration),
+    /// Does not count the entities, only a link to the next page is shown
+    None,
+}
+
+/// Maximum number of counts kept by the [ActixAdminCountCache], the oldest count is evicted first
+pub const MAX_CACHED_COUNTS: usize = 1000;
+
+#[derive(Clone, Debug)]
+struct CachedCount {
+    inserted: Instant,
+    ttl: Duration,
+    count: u64,
+}
+
+/// Counts of entities by search and filter values, shared by the clones of an ActixAdmin
+#[derive(Clone, Debug, Default)]
+pub struct ActixAdminCountCache {
+    counts: Arc<Mutex<HashMap<String, CachedCount>>>,
+}
+
+impl ActixAdminCountCache {
+    /// Key of the count of a list, built like its encoded query string
+    pub fn get_key(entity_name: &str, filters: &[ActixAdminViewModelFilter], search: &str) -> String {
+        let mut filter_values: Vec<String> = filters
+            .iter()
+            .filter_map(|f| f.value.as_ref().map(|v| format!("{}={}", encode(&f.name), encode(v))))
+            .collect();
+        filter_values.sort();
+
+        format!("{}?search={}&{}", encode(entity_name), encode(search), filter_values.join("&"))
+    }
+
+    pub fn get(&self, key: &str, ttl: &Duration) -> Option<u64> {
+        let mut counts = self.counts.lock().unwrap();
+        match counts.get(key) {
+            Some(cached) if cached.inserted.elapsed() < *ttl => Some(cached.count),
+            Some(_) => {
+                counts.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Inserts a count which expires after the ttl, expired counts are removed and the oldest
+    /// counts are evicted if the cache is full
+    pub fn insert(&self, key: String, count: u64, ttl: &Duration) {
+        let mut counts = self.counts.lock().unwrap();
+        counts.retain(|_, cached| cached.inserted.elapsed() < cached.ttl);
+        while counts.len() >= MAX_CACHED_COUNTS && !counts.contains_key(&key) {
+            let oldest = counts
+                .iter()
+                .min_by_key(|(_, cached)| cached.inserted)
+                .map(|(key, _)| key.to_string());
+            match oldest {
+                Some(oldest) => counts.remove(&oldest),
+                None => break,
+            };
+        }
+        counts.insert(key, CachedCount { inserted: Instant::now(), ttl: *ttl, count });
+    }
+
+    pub fn len(&self) -> usize {
+        self.counts.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.lock().unwrap().is_empty()
+    }
+}
+
+/// Position of a keyset page, relative to the primary key of a row.
+/// Like the show, edit and delete views, keyset pagination only supports `i32` primary keys.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ActixAdminCursor {
+    After(i32),
+    Before(i32),
+}
+
+impl ActixAdminCursor {
+    pub fn is_before(&self) -> bool {
+        matches!(self, ActixAdminCursor::Before(_))
+    }
+}
+
+/// Orders the query by the sort column and the primary key as tiebreaker and,
+/// if a cursor is given, restricts it to the rows after or before the cursor row.
+/// For a cursor before a row the order is reversed, the fetched rows need to be reversed again.
+pub fn apply_sort_and_cursor<E: EntityTrait>(
+    query: Select<E>,
+    sort_column: E::Column,
+    primary_key_column: E::Column,
+    is_sorted_by_primary_key: bool,
+    sort_order: &SortOrder,
+    cursor: Option<&ActixAdminCursor>,
+) -> Select<E> {
+    let is_before = cursor.is_some_and(|c| c.is_before());
+    let is_ascending = sort_order.eq(&SortOrder::Asc) != is_before;
+    let order = if is_ascending { Order::Asc } else { Order::Desc };
+
+    let mut query = query.order_by(sort_column, order.clone());
+    if !is_sorted_by_primary_key {
+        query = query.order_by(primary_key_column, order);
+    }
+
+    let id = match cursor {
+        Some(ActixAdminCursor::After(id)) | Some(ActixAdminCursor::Before(id)) => *id,
+        None => return query,
+    };
+
+    let compare = |column: E::Column, value: SimpleExpr| -> SimpleExpr {
+        if is_ascending {
+            Expr::expr(column.into_simple_expr()).gt(value)
+        } else {
+            Expr::expr(column.into_simple_expr()).lt(value)
+        }
+    };
+
+    if is_sorted_by_primary_key {
+        return query.filter(compare(primary_key_column, id.into()));
+    }
+
+    let cursor_table = Alias::new("actix_admin_cursor");
+    let cursor_row: SelectStatement = Query::select()
+        .column((cursor_table.clone(), sort_column))
+        .from_as(E::default(), cursor_table.clone())
+        .and_where(Expr::col((cursor_table, primary_key_column)).eq(id))
+        .to_owned();
+    let cursor_value = SimpleExpr::SubQuery(
+        None,
+        Box::new(SubQueryStatement::SelectStatement(cursor_row)),
+    );
+
+    query.filter(
+        Condition::any()
+            .add(compare(sort_column, cursor_value.clone()))
+            .add(
+                Condition::all()
+                    .add(Expr::expr(sort_column.into_simple_expr()).eq(cursor_value))
+                    .add(compare(primary_key_column, id.into())),
+            ),
+    )
+}
+
+/// Returns the number of rows of a table according to the statistics of the database.
+/// Sqlite does not keep such statistics, None is returned in this case.
+pub async fn get_estimated_count(
+    db: &DatabaseConnection,
+    table_name: &str,
+) -> Result<Option<u64>, ActixAdminError> {
+    let backend = db.get_database_backend();
+    let stmt = match backend {
+        DatabaseBackend::Postgres => Statement::from_sql_and_values(
+            backend,
+            "SELECT reltuples::bigint AS count FROM pg_class WHERE relname = $1",
+            [table_name.into()],
+        ),
+        DatabaseBackend::MySql => Statement::from_sql_and_values(
+            backend,
+            "SELECT CAST(table_rows AS SIGNED) AS count FROM information_schema.tables WHERE table_schema = DATABASE() AND table_name = ?",
+            [table_name.into()],
+        ),
+        DatabaseBackend::Sqlite => return Ok(None),
+    };
+
+    let row = db.query_one(stmt).await?;
+    match row {
+        Some(row) => {
+            // postgres reports -1 for tables which have not been analyzed yet
+            let count: i64 = row.try_get("", "count")?;
+            Ok(u64::try_from(count).ok())
+        }
+        None => Ok(None),
+    }
+}
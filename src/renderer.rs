@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use tera::{to_value, Filter, Tera};
+
+use crate::view_model::ActixAdminViewModelField;
+use crate::ActixAdminModel;
+
+/// Renders the value of a field as html from the value, the field and the model it belongs to.
+/// The returned html is not escaped.
+pub type ActixAdminRendererFn = fn(&str, &ActixAdminViewModelField, &ActixAdminModel) -> String;
+
+/// A named renderer which is assigned to fields with `#[actix_admin(renderer = "name")]`
+#[derive(Clone)]
+pub enum ActixAdminRenderer {
+    /// Tera filter which is called with the value and the arguments `field` and `model`
+    Filter(Arc<dyn Filter>),
+    /// Rust function from the value, the field and the model to html
+    Fn(ActixAdminRendererFn),
+}
+
+/// Registers the filter `render_field`, which renders a value with the renderer of the field
+/// given as `field` argument. Values of fields without a known renderer are escaped.
+pub fn register_renderer_filter(tera: &mut Tera, renderers: Arc<HashMap<String, ActixAdminRenderer>>) {
+    tera.register_filter("render_field", move |value: &tera::Value, args: &HashMap<String, tera::Value>| {
+        let field = args
+            .get("field")
+            .and_then(|field| serde_json::from_value::<ActixAdminViewModelField>(field.clone()).ok());
+        let renderer = field
+            .as_ref()
+            .and_then(|field| field.renderer.as_ref())
+            .and_then(|renderer| renderers.get(renderer));
+
+        let value_str = match value {
+            tera::Value::String(s) => s.to_string(),
+            other => other.to_string(),
+        };
+
+        match (renderer, field) {
+            (Some(ActixAdminRenderer::Filter(filter)), _) => filter.filter(value, args),
+            (Some(ActixAdminRenderer::Fn(render)), Some(field)) => {
+                let model = args
+                    .get("model")
+                    .and_then(|model| serde_json::from_value::<ActixAdminModel>(model.clone()).ok())
+                    .unwrap_or_else(ActixAdminModel::create_empty);
+                Ok(to_value(render(&value_str, &field, &model)).unwrap())
+            }
+            _ => Ok(to_value(tera::escape_html(&value_str)).unwrap()),
+        }
+    });
+}
@@ -5,10 +5,11 @@ use actix_session::{Session};
 use crate::ActixAdminError;
 use crate::ActixAdminNotification;
 use crate::prelude::*;
+use crate::timezone::{get_timezone, localize_model};
 
 use super::DEFAULT_ENTITIES_PER_PAGE;
 use super::Params;
-use super::{ add_auth_context, user_can_access_page, render_unauthorized};
+use super::{ add_auth_context, add_locale_context, get_template_name, render_error, user_can_access_page, render_unauthorized};
 
 pub async fn create_get<E: ActixAdminViewModelTrait>(
     session: Session,
@@ -41,11 +42,11 @@ pub async fn edit_get<E: ActixAdminViewModelTrait>(
 async fn create_or_edit_get<E: ActixAdminViewModelTrait>(session: &Session, req: HttpRequest, data: &web::Data<ActixAdmin>, db: &sea_orm::DatabaseConnection, model_result: Result<ActixAdminModel, ActixAdminError>) -> Result<HttpResponse, Error>{
     let actix_admin = &data.get_ref();
     let mut ctx = Context::new();
+    add_locale_context(&req, session, actix_admin, &mut ctx);
     add_auth_context(&session, actix_admin, &mut ctx);
     let entity_names = &actix_admin.entity_names;
     ctx.insert("entity_names", entity_names);
     let entity_name = E::get_entity_name();
-    let mut errors: Vec<crate::ActixAdminError> = Vec::new();
 
     let view_model = actix_admin.view_models.get(&entity_name).unwrap();
 
@@ -53,26 +54,13 @@ async fn create_or_edit_get<E: ActixAdminViewModelTrait>(session: &Session, req:
         return render_unauthorized(&ctx, &actix_admin);
     }
 
-    let model;
-    match model_result {
-        Ok(res) => {
-            model = res;
-        },
-        Err(e) => {
-            #[cfg(feature="enable-tracing")]
-            tracing::error!("{e}");
-            errors.push(e);
-            model = ActixAdminModel::create_empty();
-        }
-    }
+    let mut model = match model_result {
+        Ok(model) => model,
+        Err(err) => return render_error(&ctx, actix_admin, &entity_name, &err),
+    };
 
-    let mut http_response_code = match errors.is_empty() {
-        true => HttpResponse::Ok(),
-        false => HttpResponse::InternalServerError(),
-    };    
-    let notifications: Vec<ActixAdminNotification> = errors.into_iter()
-        .map(|err| ActixAdminNotification::from(err))
-        .collect();
+    localize_model(view_model.fields, &mut model, &get_timezone(session, actix_admin));
+    let notifications: Vec<ActixAdminNotification> = Vec::new();
 
     let params = web::Query::<Params>::from_query(req.query_string()).unwrap();
 
@@ -87,6 +75,7 @@ async fn create_or_edit_get<E: ActixAdminViewModelTrait>(session: &Session, req:
 
     ctx.insert("view_model", &ActixAdminViewModelSerializable::from(view_model.clone()));
     ctx.insert("select_lists", &E::get_select_lists(db).await?);
+    ctx.insert("entity_name", &entity_name);
     ctx.insert("base_path", &E::get_base_path(&entity_name));
     ctx.insert("model", &model);
     ctx.insert("notifications", &notifications);
@@ -98,11 +87,11 @@ async fn create_or_edit_get<E: ActixAdminViewModelTrait>(session: &Session, req:
     ctx.insert("page", &page);
     
     let body = actix_admin.tera
-        .render("create_or_edit.html", &ctx)
+        .render(&get_template_name(actix_admin, &entity_name, "create_or_edit.html"), &ctx)
         .map_err(|err| {
             #[cfg(feature="enable-tracing")]
             tracing::error!("{err}");
             error::ErrorInternalServerError(err)
         })?;
-    Ok(http_response_code.content_type("text/html").body(body))
+    Ok(HttpResponse::Ok().content_type("text/html").body(body))
 }
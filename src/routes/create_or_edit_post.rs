@@ -1,8 +1,9 @@
-use super::{render_unauthorized, user_can_access_page};
+use super::{accepts_json, add_auth_context, add_locale_context, get_http_response_code, get_template_name, render_unauthorized, user_can_access_page};
 use super::{Params, DEFAULT_ENTITIES_PER_PAGE};
 use crate::prelude::*;
 use crate::ActixAdminError;
 use crate::ActixAdminNotification;
+use crate::timezone::{delocalize_model, get_timezone, localize_model};
 use actix_multipart::Multipart;
 use actix_multipart::MultipartError;
 use actix_session::Session;
@@ -82,14 +83,27 @@ pub async fn create_or_edit_post<E: ActixAdminViewModelTrait>(
     let db = db.get_ref();
 
     let mut model = model_res.unwrap();
+    model.primary_key = id.map(|id| id.to_string());
+    let timezone = get_timezone(session, actix_admin);
+    delocalize_model(view_model.fields, &mut model, &timezone);
     #[cfg(feature="enable-tracing")]
     {
         tracing::debug!("Entity model: {:#?}", model);
     }
     E::validate_entity(&mut model);
 
-    if model.has_errors() {
-        errors.push(ActixAdminError::ValidationErrors);
+    let mode = match id {
+        Some(id) => ActixAdminValidationMode::Edit(id),
+        None => ActixAdminValidationMode::Create,
+    };
+    if let Err(e) = E::validate_entity_async(db, session, &mode, &mut model).await {
+        errors.push(e);
+    }
+
+    if !errors.is_empty() || model.has_errors() {
+        if model.has_errors() {
+            errors.push(ActixAdminError::ValidationErrors);
+        }
         #[cfg(feature="enable-tracing")]
         {
             tracing::error!("OP errors: {errors:#?}");
@@ -97,6 +111,7 @@ pub async fn create_or_edit_post<E: ActixAdminViewModelTrait>(
         }
         render_form::<E>(
             req,
+            session,
             actix_admin,
             view_model,
             &db,
@@ -139,6 +154,7 @@ pub async fn create_or_edit_post<E: ActixAdminViewModelTrait>(
                 errors.push(e);
                 render_form::<E>(
                     req,
+                    session,
                     actix_admin,
                     view_model,
                     &db,
@@ -152,8 +168,10 @@ pub async fn create_or_edit_post<E: ActixAdminViewModelTrait>(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn render_form<E: ActixAdminViewModelTrait>(
     req: HttpRequest,
+    session: &Session,
     actix_admin: &ActixAdmin,
     view_model: &ActixAdminViewModel,
     db: &&sea_orm::DatabaseConnection,
@@ -161,6 +179,23 @@ async fn render_form<E: ActixAdminViewModelTrait>(
     model: &ActixAdminModel,
     errors: Vec<ActixAdminError>,
 ) -> Result<HttpResponse, Error> {
+    let mut model = model.clone();
+    localize_model(view_model.fields, &mut model, &get_timezone(session, actix_admin));
+
+    // after a conflict the form carries the current version, so submitting it again overwrites the concurrent change
+    let conflict = errors.iter().find_map(|err| match err {
+        ActixAdminError::ConflictError(conflict) => Some(conflict.clone()),
+        _ => None,
+    });
+    if let (Some(conflict), Some(version_field)) = (&conflict, &view_model.version_field) {
+        model.values.insert(version_field.to_string(), conflict.version.clone());
+    }
+
+    // clients which accept json get the model with its field and form errors instead of the form
+    if accepts_json(&req) {
+        return Ok(get_http_response_code(&errors).json(&model));
+    }
+
     let mut ctx = Context::new();
 
     let params = web::Query::<Params>::from_query(req.query_string()).unwrap();
@@ -184,34 +219,38 @@ async fn render_form<E: ActixAdminViewModelTrait>(
     ctx.insert("sort_order", &sort_order);
     ctx.insert("page", &page);
 
-    ctx.insert("navbar_title", &actix_admin.configuration.navbar_title);
     ctx.insert("entity_names", &actix_admin.entity_names);
+    add_locale_context(&req, session, actix_admin, &mut ctx);
+    add_auth_context(session, actix_admin, &mut ctx);
     ctx.insert(
         "view_model",
         &ActixAdminViewModelSerializable::from(view_model.clone()),
     );
     ctx.insert("select_lists", &E::get_select_lists(db).await?);
+    ctx.insert("entity_name", &entity_name);
     ctx.insert("base_path", &E::get_base_path(&entity_name));
-    ctx.insert("model", model);
+    ctx.insert("model", &model);
+    ctx.insert("conflict", &conflict);
 
+    let mut http_response_code = get_http_response_code(&errors);
     let notifications: Vec<ActixAdminNotification> = errors
         .into_iter()
         .map(|err| {
             #[cfg(feature="enable-tracing")]
-            tracing::error!("{err}");
+            tracing::error!("{}", err.get_error_chain().join(": "));
             ActixAdminNotification::from(err)
         })
         .collect();
 
     ctx.insert("notifications", &notifications);
     let body = actix_admin.tera
-        .render("create_or_edit.html", &ctx)
+        .render(&get_template_name(actix_admin, &entity_name, "create_or_edit.html"), &ctx)
         .map_err(|err| {
             #[cfg(feature="enable-tracing")]
             tracing::error!("{err}");
             error::ErrorInternalServerError(err)
         })?;
-    Ok(HttpResponse::Ok().content_type("text/html").body(body))
+    Ok(http_response_code.content_type("text/html").body(body))
 }
 
 #[doc(hidden)]
@@ -234,6 +273,7 @@ impl From<String> for ActixAdminModel {
             values: hashmap,
             errors: HashMap::new(),
             custom_errors: HashMap::new(),
+            form_errors: Vec::new(),
         }
     }
 }
@@ -1,8 +1,10 @@
-use super::{render_unauthorized, user_can_access_page};
+use super::{accepts_json, add_locale_context, get_template_name, render_unauthorized, user_can_access_page};
 use crate::prelude::*;
+use crate::{ActixAdminNotification, ActixAdminNotificationType};
 use actix_session::Session;
 use actix_web::http::header;
-use actix_web::{web, Error, HttpRequest, HttpResponse};
+use actix_web::{error, web, Error, HttpRequest, HttpResponse, ResponseError};
+use serde_derive::Serialize;
 use sea_orm::DatabaseConnection;
 use tera::Context;
 
@@ -51,13 +53,17 @@ pub async fn delete<E: ActixAdminViewModelTrait>(
 
             Ok(HttpResponse::Ok().finish())
         }
-        (_, _) => Ok(HttpResponse::InternalServerError().finish()),
+        (Err(err), _) | (_, Err(err)) => {
+            #[cfg(feature="enable-tracing")]
+            tracing::error!("{}", err.get_error_chain().join(": "));
+            Ok(HttpResponse::build(err.status_code()).finish())
+        }
     }
 }
 
 pub async fn delete_many<E: ActixAdminViewModelTrait>(
     session: Session,
-    _req: HttpRequest,
+    req: HttpRequest,
     data: web::Data<ActixAdmin>,
     db: web::Data<DatabaseConnection>,
     form: web::Form<Vec<(String, String)>>,
@@ -66,7 +72,6 @@ pub async fn delete_many<E: ActixAdminViewModelTrait>(
     let entity_name = E::get_entity_name();
 
     let view_model = actix_admin.view_models.get(&entity_name).unwrap();
-    let mut errors: Vec<crate::ActixAdminError> = Vec::new();
 
     if !user_can_access_page(&session, actix_admin, view_model) {
         let mut ctx = Context::new();
@@ -77,33 +82,35 @@ pub async fn delete_many<E: ActixAdminViewModelTrait>(
     let db = &db.get_ref();
     let entity_name = E::get_entity_name();
 
-    let ids: Vec<i32> = form.iter().filter(|el| el.0 == "ids").map(|el| el.1.parse::<i32>().unwrap()).collect();
-
-    // TODO: implement delete_many
-    for id in ids {
-        let model_result = E::get_entity(db, id).await;
-        let delete_result = E::delete_entity(db, id).await;
-        match (delete_result, model_result) {
-            (Err(e), _) => errors.push(e),
-            (Ok(_), Ok(model)) => {
-                for field in view_model.fields {
-                    if field.field_type == ActixAdminViewModelFieldType::FileUpload {
-                        let file_name = model
-                            .get_value::<String>(&field.field_name, true, true)
-                            .unwrap_or_default();
-                        if file_name.is_some() {
-                            let file_path = format!(
-                                "{}/{}/{}",
-                                actix_admin.configuration.file_upload_directory,
-                                E::get_entity_name(),
-                                file_name.unwrap_or_default()
-                            );
-                            std::fs::remove_file(file_path)?;
-                        }
+    let mut ids: Vec<i32> = Vec::new();
+    let mut failures: Vec<(Option<i32>, ActixAdminError)> = Vec::new();
+    for (_, id) in form.iter().filter(|el| el.0 == "ids") {
+        match id.parse::<i32>() {
+            Ok(id) => ids.push(id),
+            Err(_) => failures.push((None, ActixAdminError::InvalidIdError(id.to_string()))),
+        }
+    }
+    ids.sort_unstable();
+    ids.dedup();
+
+    // like a failed id, an invalid id prevents the whole delete
+    if failures.is_empty() {
+        match E::delete_entities(db, &ids).await {
+            // files are only removed after the delete is committed
+            Ok(models) => {
+                for model in models {
+                    for _err in remove_files(actix_admin, view_model, &model) {
+                        #[cfg(feature="enable-tracing")]
+                        tracing::error!("{_err}");
+                        let id = model.primary_key.as_deref().and_then(|id| id.parse::<i32>().ok());
+                        failures.push((id, ActixAdminError::InternalError));
                     }
                 }
             }
-            (Ok(_), Err(e)) => errors.push(e),
+            Err(ActixAdminError::DeleteManyError(id_failures)) => {
+                failures.extend(id_failures.into_iter().map(|(id, err)| (Some(id), err)));
+            }
+            Err(err) => failures.push((None, err)),
         }
     }
 
@@ -128,13 +135,92 @@ pub async fn delete_many<E: ActixAdminViewModelTrait>(
         .map(|e| e.1.to_string())
         .unwrap_or("1".to_string());
 
-    match errors.is_empty() {
-        true => Ok(HttpResponse::SeeOther()
+    if failures.is_empty() {
+        return Ok(HttpResponse::SeeOther()
             .append_header((
                 header::LOCATION,
                 format!("/admin/{}/list?entities_per_page={}&search={}&sort_by={}&sort_order={}&page={}", entity_name, entities_per_page, search, sort_by, sort_order, page),
             ))
-            .finish()),
-        false => Ok(HttpResponse::InternalServerError().finish()),
+            .finish());
+    }
+
+    render_delete_failures(&req, &session, actix_admin, &entity_name, failures)
+}
+
+#[derive(Serialize)]
+struct ActixAdminDeleteFailure {
+    id: Option<i32>,
+    error: String,
+}
+
+/// Responds with the failures of a bulk delete, as json or as notifications which replace the
+/// notifications of the page
+fn render_delete_failures(
+    req: &HttpRequest,
+    session: &Session,
+    actix_admin: &ActixAdmin,
+    entity_name: &str,
+    failures: Vec<(Option<i32>, ActixAdminError)>,
+) -> Result<HttpResponse, Error> {
+    let mut http_response_code = HttpResponse::build(failures[0].1.status_code());
+
+    if accepts_json(req) {
+        let failures: Vec<ActixAdminDeleteFailure> = failures
+            .into_iter()
+            .map(|(id, err)| ActixAdminDeleteFailure { id, error: err.to_string() })
+            .collect();
+        return Ok(http_response_code.json(failures));
+    }
+
+    let notifications: Vec<ActixAdminNotification> = failures
+        .into_iter()
+        .map(|(id, err)| {
+            #[cfg(feature="enable-tracing")]
+            tracing::error!("{}", err.get_error_chain().join(": "));
+            let message = match id {
+                Some(id) => format!("{}: {}", id, err),
+                None => err.to_string(),
+            };
+            ActixAdminNotification::new(ActixAdminNotificationType::Danger, message)
+        })
+        .collect();
+
+    let mut ctx = Context::new();
+    add_locale_context(req, session, actix_admin, &mut ctx);
+    ctx.insert("notifications", &notifications);
+    let body = actix_admin.tera
+        .render(&get_template_name(actix_admin, entity_name, "notifications.html"), &ctx)
+        .map_err(|err| {
+            #[cfg(feature="enable-tracing")]
+            tracing::error!("{err}");
+            error::ErrorInternalServerError(err)
+        })?;
+    Ok(http_response_code
+        .insert_header(("HX-Retarget", "#notifications"))
+        .content_type("text/html")
+        .body(body))
+}
+
+/// Removes the uploaded files of a deleted entity, a failing file does not stop the removal of the others
+fn remove_files(actix_admin: &ActixAdmin, view_model: &ActixAdminViewModel, model: &ActixAdminModel) -> Vec<std::io::Error> {
+    let mut errors = Vec::new();
+    for field in view_model.fields {
+        if field.field_type == ActixAdminViewModelFieldType::FileUpload {
+            let file_name = model
+                .get_value::<String>(&field.field_name, true, true)
+                .unwrap_or_default();
+            if let Some(file_name) = file_name {
+                let file_path = format!(
+                    "{}/{}/{}",
+                    actix_admin.configuration.file_upload_directory,
+                    view_model.entity_name,
+                    file_name
+                );
+                if let Err(err) = std::fs::remove_file(file_path) {
+                    errors.push(err);
+                }
+            }
+        }
     }
+    errors
 }
@@ -4,7 +4,7 @@ use sea_orm::DatabaseConnection;
 use tera::{Context};
 use crate::prelude::*;
 
-use super::{ user_can_access_page, render_unauthorized};
+use super::{ add_locale_context, user_can_access_page, render_unauthorized};
 
 pub async fn download<E: ActixAdminViewModelTrait>(req: HttpRequest, session: Session, data: web::Data<ActixAdmin>, db: web::Data<DatabaseConnection>, params: web::Path<(i32, String)>) -> Result<HttpResponse, Error> {
     let actix_admin = &data.into_inner();
@@ -42,7 +42,7 @@ pub async fn download<E: ActixAdminViewModelTrait>(req: HttpRequest, session: Se
     
 }
 
-pub async fn delete_file<E: ActixAdminViewModelTrait>(session: Session, data: web::Data<ActixAdmin>, db: web::Data<DatabaseConnection>, params: web::Path<(i32, String)>) -> Result<HttpResponse, Error> {
+pub async fn delete_file<E: ActixAdminViewModelTrait>(req: HttpRequest, session: Session, data: web::Data<ActixAdmin>, db: web::Data<DatabaseConnection>, params: web::Path<(i32, String)>) -> Result<HttpResponse, Error> {
     let actix_admin = &data.into_inner();
 
     let mut ctx = Context::new();
@@ -73,6 +73,7 @@ pub async fn delete_file<E: ActixAdminViewModelTrait>(session: Session, data: we
     let _edit_res = E::edit_entity(db.get_ref(), id, model.clone()).await;
 
     let view_model_field = &view_model.fields.iter().find(|field| field.field_name == column_name).unwrap();
+    add_locale_context(&req, &session, actix_admin, &mut ctx);
     ctx.insert("model_field", view_model_field);
     ctx.insert("base_path", &E::get_base_path(&entity_name));
     ctx.insert("model", &model);
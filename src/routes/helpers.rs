@@ -2,13 +2,19 @@ use actix_session::{Session};
 use tera::{Context};
 
 use crate::prelude::*;
-use actix_web::{error, Error, HttpResponse};
+use crate::assets::{get_admin_assets_path, get_asset_urls};
+use crate::i18n::get_locale;
+use actix_web::{error, Error, HttpRequest, HttpResponse, HttpResponseBuilder, ResponseError};
 
 
 pub fn add_auth_context(session: &Session, actix_admin: &ActixAdmin, ctx: &mut Context) {
     let enable_auth = &actix_admin.configuration.enable_auth;
     ctx.insert("enable_auth", &enable_auth);
-    ctx.insert("navbar_title", &actix_admin.configuration.navbar_title);
+    add_layout_context(actix_admin, ctx);
+    // without the request the locale can only be taken from the session or configuration
+    if !ctx.contains_key("locale") {
+        ctx.insert("locale", &get_locale(None, session, actix_admin));
+    }
     if *enable_auth {
         let func = &actix_admin.configuration.user_is_logged_in.unwrap();
         ctx.insert("user_is_logged_in", &func(session));
@@ -17,6 +23,39 @@ pub fn add_auth_context(session: &Session, actix_admin: &ActixAdmin, ctx: &mut C
     }
 }
 
+/// Adds the variables which are used by base.html, head.html and navbar.html
+fn add_layout_context(actix_admin: &ActixAdmin, ctx: &mut Context) {
+    ctx.insert("navbar_title", &actix_admin.configuration.navbar_title);
+    ctx.insert("asset_urls", &get_asset_urls(&actix_admin.configuration));
+    ctx.insert("admin_assets_path", &get_admin_assets_path());
+    ctx.insert("theme", &actix_admin.configuration.theme);
+    if !ctx.contains_key("entity_names") {
+        ctx.insert("entity_names", &actix_admin.entity_names);
+    }
+}
+
+/// Returns the entity-specific template, e.g. `post/list.html`, if it was added to the builder,
+/// otherwise the template itself. Pages without entity, such as the global search, pass an empty entity name.
+pub fn get_template_name(actix_admin: &ActixAdmin, entity_name: &str, template: &str) -> String {
+    let entity_template = format!("{}/{}", entity_name, template);
+    match !entity_name.is_empty() && actix_admin.tera.get_template_names().any(|name| name == entity_template) {
+        true => entity_template,
+        false => template.to_string(),
+    }
+}
+
+pub fn add_locale_context(req: &HttpRequest, session: &Session, actix_admin: &ActixAdmin, ctx: &mut Context) {
+    let mut locales: Vec<(&String, &String)> = actix_admin
+        .locales
+        .iter()
+        .map(|(locale_code, locale)| (locale_code, &locale.name))
+        .collect();
+    locales.sort();
+
+    ctx.insert("locale", &get_locale(Some(req), session, actix_admin));
+    ctx.insert("locales", &locales);
+}
+
 pub fn user_can_access_page(session: &Session, actix_admin: &ActixAdmin, view_model: &ActixAdminViewModel) -> bool {
     let auth_is_enabled = &actix_admin.configuration.enable_auth;
     let user_is_logged_in = &actix_admin.configuration.user_is_logged_in;
@@ -30,8 +69,85 @@ pub fn user_can_access_page(session: &Session, actix_admin: &ActixAdmin, view_mo
 }
 
 pub fn render_unauthorized(ctx: &Context, actix_admin: &ActixAdmin) -> Result<HttpResponse, Error> {
+    let mut ctx = ctx.clone();
+    if !ctx.contains_key("locale") {
+        ctx.insert("locale", &actix_admin.configuration.default_locale);
+    }
+    if !ctx.contains_key("theme") {
+        add_layout_context(actix_admin, &mut ctx);
+    }
     let body = actix_admin.tera
             .render("unauthorized.html", &ctx)
             .map_err(|err| error::ErrorInternalServerError(err))?;
     Ok(HttpResponse::Unauthorized().content_type("text/html").body(body))
-}
\ No newline at end of file
+}
+
+/// Renders the error page of the entity with the status code of the error. The sources of the error are
+/// only shown in debug mode.
+pub fn render_error(ctx: &Context, actix_admin: &ActixAdmin, entity_name: &str, err: &ActixAdminError) -> Result<HttpResponse, Error> {
+    let error_chain = err.get_error_chain();
+    #[cfg(feature="enable-tracing")]
+    tracing::error!("{}", error_chain.join(": "));
+
+    let mut ctx = ctx.clone();
+    if !ctx.contains_key("locale") {
+        ctx.insert("locale", &actix_admin.configuration.default_locale);
+    }
+    if !ctx.contains_key("theme") {
+        add_layout_context(actix_admin, &mut ctx);
+    }
+    let status_code = err.status_code();
+    ctx.insert("status_code", &status_code.as_u16());
+    ctx.insert("error_message", &err.to_string());
+    if actix_admin.configuration.debug_mode {
+        ctx.insert("error_details", &error_chain[1..]);
+    }
+
+    let body = actix_admin.tera
+            .render(&get_template_name(actix_admin, entity_name, "error.html"), &ctx)
+            .map_err(error::ErrorInternalServerError)?;
+    Ok(HttpResponse::build(status_code).content_type("text/html").body(body))
+}
+
+/// Returns the response builder with the status code of the first error, or 200 if there are none
+pub fn get_http_response_code(errors: &[ActixAdminError]) -> HttpResponseBuilder {
+    match errors.first() {
+        Some(err) => HttpResponse::build(err.status_code()),
+        None => HttpResponse::Ok(),
+    }
+}
+
+/// Whether the client accepts json, e.g. a script instead of htmx
+pub fn accepts_json(req: &HttpRequest) -> bool {
+    req.headers()
+        .get(actix_web::http::header::ACCEPT)
+        .and_then(|accept| accept.to_str().ok())
+        .is_some_and(|accept| accept.contains("application/json"))
+}
+
+/// Redirects back to the page in the Referer header if it is a page of the admin on the same origin,
+/// otherwise to the index of the admin
+pub fn redirect_back(req: &HttpRequest) -> HttpResponse {
+    let connection_info = req.connection_info();
+    let location = req
+        .headers()
+        .get(actix_web::http::header::REFERER)
+        .and_then(|referer| referer.to_str().ok())
+        .and_then(|referer| referer.parse::<actix_web::http::Uri>().ok())
+        .filter(|uri| match (uri.scheme_str(), uri.authority()) {
+            (Some(scheme), Some(authority)) => scheme == connection_info.scheme() && authority.as_str() == connection_info.host(),
+            (None, None) => true,
+            _ => false,
+        })
+        .and_then(|uri| uri.path_and_query().map(|path_and_query| path_and_query.to_string()))
+        .filter(|path| path.starts_with("/admin/") && !path.contains("/../"))
+        .unwrap_or("/admin/".to_string());
+
+    HttpResponse::SeeOther()
+        .append_header((actix_web::http::header::LOCATION, location))
+        .finish()
+}
+
+pub fn get_user_identifier(session: &Session, actix_admin: &ActixAdmin) -> Option<String> {
+    actix_admin.configuration.user_identifier.and_then(|func| func(session))
+}
@@ -1,10 +1,10 @@
-use actix_web::{error, web, Error, HttpResponse};
+use actix_web::{error, web, Error, HttpRequest, HttpResponse};
 use actix_session::{Session};
 use tera::{Context};
 
 use crate::prelude::*;
 
-use super::{ add_auth_context };
+use super::{ add_auth_context, add_locale_context, redirect_back };
 
 pub fn get_admin_ctx(session: Session, data: &web::Data<ActixAdmin>) -> Context {
     let actix_admin = data.get_ref();
@@ -17,7 +17,7 @@ pub fn get_admin_ctx(session: Session, data: &web::Data<ActixAdmin>) -> Context
     ctx
 }
 
-pub async fn index(session: Session, data: web::Data<ActixAdmin>) -> Result<HttpResponse, Error> {
+pub async fn index(session: Session, req: HttpRequest, data: web::Data<ActixAdmin>) -> Result<HttpResponse, Error> {
     let actix_admin = &data.into_inner();
     let notifications: Vec<crate::ActixAdminNotification> = Vec::new();
 
@@ -25,6 +25,7 @@ pub async fn index(session: Session, data: web::Data<ActixAdmin>) -> Result<Http
     ctx.insert("entity_names", &actix_admin.entity_names);
     ctx.insert("notifications", &notifications);    
 
+    add_locale_context(&req, &session, actix_admin, &mut ctx);
     add_auth_context(&session, actix_admin, &mut ctx);
 
     let body = actix_admin.tera
@@ -37,9 +38,13 @@ pub async fn index(session: Session, data: web::Data<ActixAdmin>) -> Result<Http
     Ok(HttpResponse::Ok().content_type("text/html").body(body))
 }
 
-pub async fn not_found(data: web::Data<ActixAdmin>) -> Result<HttpResponse, Error> {
+pub async fn not_found(session: Session, req: HttpRequest, data: web::Data<ActixAdmin>) -> Result<HttpResponse, Error> {
+    let mut ctx = Context::new();
+    add_locale_context(&req, &session, data.get_ref(), &mut ctx);
+    add_auth_context(&session, data.get_ref(), &mut ctx);
+
     let body = data.get_ref().tera
-        .render("not_found.html", &Context::new())
+        .render("not_found.html", &ctx)
         .map_err(|e| {
             #[cfg(feature="enable-tracing")]
             tracing::error!("{}", e);
@@ -48,3 +53,26 @@ pub async fn not_found(data: web::Data<ActixAdmin>) -> Result<HttpResponse, Erro
     Ok(HttpResponse::NotFound().content_type("text/html").body(body))
 }
 
+
+
+/// Stores the locale in the session and redirects back to the previous page
+pub async fn set_locale(session: Session, req: HttpRequest, data: web::Data<ActixAdmin>, locale: web::Path<String>) -> Result<HttpResponse, Error> {
+    let locale = locale.into_inner();
+    if !data.get_ref().locales.contains_key(&locale) {
+        return Ok(HttpResponse::NotFound().finish());
+    }
+    session.insert(crate::i18n::LOCALE_SESSION_KEY, locale)?;
+
+    Ok(redirect_back(&req))
+}
+
+/// Stores the timezone in the session and redirects back to the previous page
+pub async fn set_timezone(session: Session, req: HttpRequest, timezone: web::Path<String>) -> Result<HttpResponse, Error> {
+    let timezone = timezone.into_inner();
+    if timezone.parse::<Tz>().is_err() {
+        return Ok(HttpResponse::NotFound().finish());
+    }
+    session.insert(crate::timezone::TIMEZONE_SESSION_KEY, timezone)?;
+
+    Ok(redirect_back(&req))
+}
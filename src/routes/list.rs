@@ -2,15 +2,19 @@ use std::fmt;
 use sea_orm::DatabaseConnection;
 use urlencoding::decode;
 use crate::prelude::*;
+use crate::pagination::{get_estimated_count, ActixAdminCountCache};
+use crate::timezone::{delocalize_filters, get_timezone, localize_model};
 use actix_web::{error, web, Error, HttpRequest, HttpResponse};
 use serde_derive::{Serialize, Deserialize};
 use tera::Context;
 
+use super::saved_view::get_query_string;
 use super::{
-    add_auth_context, render_unauthorized, user_can_access_page, Params, DEFAULT_ENTITIES_PER_PAGE,
+    add_auth_context, add_locale_context, get_http_response_code, get_template_name, get_user_identifier, render_unauthorized, user_can_access_page, Params,
+    DEFAULT_ENTITIES_PER_PAGE,
 };
 use crate::ActixAdminModel;
-use crate::ActixAdminNotification;
+use crate::{ActixAdminNotification, ActixAdminNotificationType};
 use crate::ActixAdminViewModel;
 use crate::ActixAdminViewModelTrait;
 use actix_session::Session;
@@ -30,7 +34,7 @@ impl fmt::Display for SortOrder {
     }
 }
 
-pub fn replace_regex(view_model: &ActixAdminViewModel, models: &mut Vec<ActixAdminModel>) {
+pub(crate) fn replace_regex(view_model: &ActixAdminViewModel, models: &mut Vec<ActixAdminModel>) {
     view_model
         .fields
         .iter()
@@ -46,6 +50,33 @@ pub fn replace_regex(view_model: &ActixAdminViewModel, models: &mut Vec<ActixAdm
         });
 }
 
+async fn get_num_entities<E: ActixAdminViewModelTrait>(
+    db: &DatabaseConnection,
+    actix_admin: &ActixAdmin,
+    view_model: &ActixAdminViewModel,
+    actixadminfilters: &[ActixAdminViewModelFilter],
+    search: &str,
+) -> Result<Option<u64>, ActixAdminError> {
+    let is_filtered = !search.is_empty() || actixadminfilters.iter().any(|f| f.value.is_some());
+
+    match &view_model.count_type {
+        ActixAdminCountType::Exact => E::count(db, actixadminfilters.to_vec(), search).await.map(Some),
+        ActixAdminCountType::Estimated if !is_filtered => get_estimated_count(db, &E::get_table_name()).await,
+        ActixAdminCountType::Cached(ttl) => {
+            let cache_key = ActixAdminCountCache::get_key(&view_model.entity_name, actixadminfilters, search);
+            match actix_admin.count_cache.get(&cache_key, ttl) {
+                Some(num_entities) => Ok(Some(num_entities)),
+                None => {
+                    let num_entities = E::count(db, actixadminfilters.to_vec(), search).await?;
+                    actix_admin.count_cache.insert(cache_key, num_entities, ttl);
+                    Ok(Some(num_entities))
+                }
+            }
+        }
+        _ => Ok(None),
+    }
+}
+
 pub async fn list<E: ActixAdminViewModelTrait>(
     session: Session,
     req: HttpRequest,
@@ -58,6 +89,7 @@ pub async fn list<E: ActixAdminViewModelTrait>(
     let mut errors: Vec<ActixAdminError> = Vec::new();
 
     let mut ctx = Context::new();
+    add_locale_context(&req, &session, actix_admin, &mut ctx);
     add_auth_context(&session, actix_admin, &mut ctx);
 
     ctx.insert("entity_names", &actix_admin.entity_names);
@@ -75,14 +107,24 @@ pub async fn list<E: ActixAdminViewModelTrait>(
     let render_partial = req.headers().contains_key("HX-Target");
     let search = params.search.clone().unwrap_or(String::new());
 
+    let is_keyset = view_model.pagination_type == ActixAdminPaginationType::Keyset;
+
+    // computed and nullable columns can only be sorted with offset pagination, rows with NULL
+    // in the sort column cannot be compared with the cursor
     let sort_by = params
         .sort_by
         .clone()
+        .filter(|sort_by| {
+            !view_model.fields.iter().any(|field| {
+                &field.field_name == sort_by
+                    && ((field.is_computed && (!field.is_sortable || is_keyset)) || (field.is_option && is_keyset))
+            })
+        })
         .unwrap_or(view_model.primary_key.to_string());
     let sort_order = params.sort_order.as_ref().unwrap_or(&SortOrder::Asc);
 
     let decoded_querystring = decode(req.query_string()).unwrap();
-    let actixadminfilters: Vec<ActixAdminViewModelFilter> = decoded_querystring
+    let mut actixadminfilters: Vec<ActixAdminViewModelFilter> = decoded_querystring
         .split("&")
         .filter(|qf| qf.starts_with("filter_"))
         .map(|f| {
@@ -90,23 +132,76 @@ pub async fn list<E: ActixAdminViewModelTrait>(
             let af = ActixAdminViewModelFilter {
                 name: kv.next().unwrap().strip_prefix("filter_").unwrap_or_default().to_string(),
                 value: kv.next().map(|s| s.to_string()).filter(|f| !f.is_empty()),
+                value_to: None,
                 values: None,
                 filter_type: None
             };
             af
         }).collect();
 
-    let result = E::list(&db, page, entities_per_page, actixadminfilters, &search, &sort_by, &sort_order).await;
+    // only range filters have a from and a to input
+    let is_range_filter = |vm_filter: &&mut ActixAdminViewModelFilter| matches!(
+        vm_filter.filter_type,
+        Some(ActixAdminModelFilterType::NumberRange | ActixAdminModelFilterType::IntegerRange | ActixAdminModelFilterType::DateRange | ActixAdminModelFilterType::DateTimeRange)
+    );
+    let mut viewmodel_filter = E::get_viewmodel_filter(&db).await;
+    for filter in &actixadminfilters {
+        if let Some(vm_filter) = viewmodel_filter.get_mut(&filter.name) {
+            vm_filter.value = filter.value.clone();
+        } else if let Some(vm_filter) = filter.name.strip_suffix("_from").and_then(|name| viewmodel_filter.get_mut(name)).filter(is_range_filter) {
+            vm_filter.value = filter.value.clone();
+        } else if let Some(vm_filter) = filter.name.strip_suffix("_to").and_then(|name| viewmodel_filter.get_mut(name)).filter(is_range_filter) {
+            vm_filter.value_to = filter.value.clone();
+        }
+    }
+
+    // the inputs keep the local datetimes, the query gets datetimes with offset
+    let timezone = get_timezone(&session, actix_admin);
+    delocalize_filters(view_model.fields, &mut actixadminfilters, &timezone);
+
+    let cursor = match (is_keyset, params.after, params.before) {
+        (true, Some(id), _) => Some(ActixAdminCursor::After(id)),
+        (true, None, Some(id)) => Some(ActixAdminCursor::Before(id)),
+        _ => None,
+    };
+
+    let num_entities = get_num_entities::<E>(&db, actix_admin, view_model, &actixadminfilters, &search).await;
+
+    let result = match num_entities {
+        Ok(num_entities) => {
+            let num_pages = num_entities.map(|n| std::cmp::max(n.div_ceil(entities_per_page), 1));
+            // only an exact count is reliable enough to limit the page
+            if let (false, ActixAdminCountType::Exact, Some(num_pages)) = (is_keyset, &view_model.count_type, num_pages) {
+                page = std::cmp::min(page, num_pages);
+            }
+            E::list(&db, page, entities_per_page, cursor.clone(), actixadminfilters, &search, &sort_by, sort_order)
+                .await
+                .map(|(entities, has_more)| (num_entities, num_pages, entities, has_more))
+        }
+        Err(e) => Err(e),
+    };
 
     match result {
-        Ok(res) => {
-            let mut entities = res.1;
+        Ok((num_entities, num_pages, mut entities, has_more)) => {
+            for entity in entities.iter_mut() {
+                localize_model(view_model.fields, entity, &timezone);
+            }
             replace_regex(view_model, &mut entities);
-            let num_pages = std::cmp::max(res.0, 1);
+            let (has_previous, has_next) = match &cursor {
+                Some(ActixAdminCursor::After(_)) => (true, has_more),
+                Some(ActixAdminCursor::Before(_)) => (has_more, true),
+                None => (!is_keyset && page > 1, has_more),
+            };
+            ctx.insert("previous_cursor", &entities.first().map(|e| e.primary_key.clone()));
+            ctx.insert("next_cursor", &entities.last().map(|e| e.primary_key.clone()));
+            ctx.insert("has_previous", &has_previous);
+            ctx.insert("has_next", &has_next);
             ctx.insert("entities", &entities);
+            ctx.insert("num_entities", &num_entities);
+
+            let num_pages = num_pages.unwrap_or(page);
             ctx.insert("num_pages", &num_pages);
-            ctx.insert("page", &std::cmp::min(num_pages, page));
-            page = std::cmp::min(page, num_pages);
+            ctx.insert("page", &page);
             let min_show_page = if &page < &5 {
                 1
             } else {
@@ -124,18 +219,30 @@ pub async fn list<E: ActixAdminViewModelTrait>(
         }
         Err(e) => {
             ctx.insert("entities", &Vec::<ActixAdminModel>::new());
+            ctx.insert("num_entities", &0);
             ctx.insert("num_pages", &0);
             ctx.insert("min_show_page", &1);
             ctx.insert("max_show_page", &1);
             ctx.insert("page", &1);
+            ctx.insert("has_previous", &false);
+            ctx.insert("has_next", &false);
             errors.push(e);
         }
     }
 
-    let mut http_response_code = match errors.is_empty() {
-        false => HttpResponse::InternalServerError(),
-        true => HttpResponse::Ok(),
-    };
+    if let Some(store) = &actix_admin.saved_view_store {
+        let user_identifier = get_user_identifier(&session, actix_admin);
+        match store.get_saved_views(&db, &entity_name, user_identifier.as_deref()).await {
+            Ok(saved_views) => ctx.insert("saved_views", &saved_views),
+            Err(_e) => {
+                #[cfg(feature="enable-tracing")]
+                tracing::error!("Could not load saved views: {_e}");
+            }
+        }
+        ctx.insert("saved_view_user_identifier", &user_identifier);
+    }
+
+    let mut http_response_code = get_http_response_code(&errors);
     let notifications: Vec<ActixAdminNotification> = errors
         .into_iter()
         .map(|err| ActixAdminNotification::from(err))
@@ -145,18 +252,28 @@ pub async fn list<E: ActixAdminViewModelTrait>(
     ctx.insert("notifications", &notifications);
     ctx.insert("entities_per_page", &entities_per_page);
     ctx.insert("render_partial", &render_partial);
-    ctx.insert("viewmodel_filter", &E::get_viewmodel_filter(&db).await);
+    ctx.insert("viewmodel_filter", &viewmodel_filter);
     ctx.insert(
         "view_model",
         &ActixAdminViewModelSerializable::from(view_model.clone()),
     );
     ctx.insert("search", &search);
+    // the pagination links keep the search, sorting, page size and filters of the current page
+    let query_params = web::Query::<Vec<(String, String)>>::from_query(req.query_string())
+        .map(|query| query.into_inner())
+        .unwrap_or_default();
+    ctx.insert("list_query_string", &get_query_string(&query_params));
+    let search_notifications: Vec<ActixAdminNotification> = E::validate_search(&search)
+        .into_iter()
+        .map(|msg| ActixAdminNotification::new(ActixAdminNotificationType::Warning, msg))
+        .collect();
+    ctx.insert("search_notifications", &search_notifications);
     ctx.insert("sort_by", &sort_by);
     ctx.insert("sort_order", &sort_order);
 
     let body = actix_admin
         .tera
-        .render("list.html", &ctx)
+        .render(&get_template_name(actix_admin, &entity_name, "list.html"), &ctx)
         .map_err(|err| error::ErrorInternalServerError(err))?;
     Ok(http_response_code.content_type("text/html").body(body))
 }
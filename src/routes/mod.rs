@@ -5,10 +5,11 @@ mod create_or_edit_post;
 pub use create_or_edit_post::{ create_post, edit_post, create_or_edit_post };
 
 mod index;
-pub use index::{ index, not_found, get_admin_ctx };
+pub use index::{ index, not_found, get_admin_ctx, set_locale, set_timezone };
 
 mod list;
 pub use list::{ list, SortOrder };
+pub(crate) use list::replace_regex;
 
 mod show;
 pub use show::show;
@@ -17,15 +18,24 @@ mod delete;
 pub use delete::{ delete, delete_many };
 
 mod helpers;
-pub use helpers::{ add_auth_context, user_can_access_page, render_unauthorized };
+pub use helpers::{ add_auth_context, add_locale_context, get_template_name, user_can_access_page, render_error, render_unauthorized, get_http_response_code, get_user_identifier, accepts_json, redirect_back };
 
 mod file;
 pub use file::{download, delete_file};
 
+mod saved_view;
+pub use saved_view::{save_view, delete_saved_view};
+
+mod search;
+pub use search::global_search;
+pub(crate) use search::search_entity;
+
 use serde_derive::{Deserialize};
 #[derive(Debug, Deserialize)]
 pub struct Params {
     page: Option<u64>,
+    after: Option<i32>,
+    before: Option<i32>,
     entities_per_page: Option<u64>,
     search: Option<String>,
     sort_by: Option<String>,
@@ -0,0 +1,109 @@
+use actix_session::Session;
+use actix_web::http::header;
+use actix_web::{web, Error, HttpResponse};
+use sea_orm::DatabaseConnection;
+use tera::Context;
+use urlencoding::encode;
+
+use crate::prelude::*;
+
+use super::{get_user_identifier, render_unauthorized, user_can_access_page};
+
+const SAVED_VIEW_PARAMS: [&str; 4] = ["search", "sort_by", "sort_order", "entities_per_page"];
+
+/// Returns the search, sorting, page size and filters of the list view as query string
+pub(crate) fn get_query_string(form: &[(String, String)]) -> String {
+    form.iter()
+        .filter(|(key, value)| {
+            (SAVED_VIEW_PARAMS.contains(&key.as_str()) || key.starts_with("filter_")) && !value.is_empty()
+        })
+        .map(|(key, value)| format!("{}={}", encode(key), encode(value)))
+        .collect::<Vec<String>>()
+        .join("&")
+}
+
+pub async fn save_view<E: ActixAdminViewModelTrait>(
+    session: Session,
+    data: web::Data<ActixAdmin>,
+    db: web::Data<DatabaseConnection>,
+    form: web::Form<Vec<(String, String)>>,
+) -> Result<HttpResponse, Error> {
+    let actix_admin = data.get_ref();
+    let entity_name = E::get_entity_name();
+    let view_model = actix_admin.view_models.get(&entity_name).unwrap();
+
+    if !user_can_access_page(&session, actix_admin, view_model) {
+        let mut ctx = Context::new();
+        ctx.insert("render_partial", &true);
+        return render_unauthorized(&ctx, actix_admin);
+    }
+
+    let store = match &actix_admin.saved_view_store {
+        Some(store) => store,
+        None => return Ok(HttpResponse::NotFound().finish()),
+    };
+
+    let name = form
+        .iter()
+        .find(|el| el.0 == "saved_view_name")
+        .map(|el| el.1.trim().to_string())
+        .unwrap_or_default();
+    if name.is_empty() {
+        return Ok(HttpResponse::BadRequest().finish());
+    }
+    let is_shared = form
+        .iter()
+        .any(|el| el.0 == "saved_view_is_shared" && el.1 == "true");
+    let query_string = get_query_string(&form);
+
+    let saved_view = ActixAdminSavedView {
+        id: None,
+        entity_name: entity_name.to_string(),
+        name,
+        user_identifier: get_user_identifier(&session, actix_admin),
+        is_shared,
+        query_string: query_string.clone(),
+    };
+    store.save_view(db.get_ref(), saved_view).await?;
+
+    Ok(HttpResponse::SeeOther()
+        .append_header((
+            header::LOCATION,
+            format!("/admin/{}/list?{}", entity_name, query_string),
+        ))
+        .finish())
+}
+
+pub async fn delete_saved_view<E: ActixAdminViewModelTrait>(
+    session: Session,
+    data: web::Data<ActixAdmin>,
+    db: web::Data<DatabaseConnection>,
+    id: web::Path<i32>,
+) -> Result<HttpResponse, Error> {
+    let actix_admin = data.get_ref();
+    let entity_name = E::get_entity_name();
+    let view_model = actix_admin.view_models.get(&entity_name).unwrap();
+
+    if !user_can_access_page(&session, actix_admin, view_model) {
+        let mut ctx = Context::new();
+        ctx.insert("render_partial", &true);
+        return render_unauthorized(&ctx, actix_admin);
+    }
+
+    let store = match &actix_admin.saved_view_store {
+        Some(store) => store,
+        None => return Ok(HttpResponse::NotFound().finish()),
+    };
+
+    let user_identifier = get_user_identifier(&session, actix_admin);
+    store
+        .delete_view(
+            db.get_ref(),
+            &entity_name,
+            id.into_inner(),
+            user_identifier.as_deref(),
+        )
+        .await?;
+
+    Ok(HttpResponse::Ok().finish())
+}
@@ -0,0 +1,121 @@
+use actix_web::{error, web, Error, HttpRequest, HttpResponse};
+use actix_session::Session;
+use futures_util::future::join_all;
+use sea_orm::DatabaseConnection;
+use serde_derive::Serialize;
+use tera::Context;
+
+use crate::prelude::*;
+use crate::search::ActixAdminSearchFuture;
+use crate::timezone::{get_timezone, localize_model};
+use crate::{ActixAdminNotification, ActixAdminViewModelSerializable};
+
+use super::{add_auth_context, add_locale_context, get_template_name, replace_regex, user_can_access_page, Params};
+
+const ENTITIES_PER_SEARCH_GROUP: u64 = 5;
+
+#[derive(Serialize)]
+struct ActixAdminSearchGroup {
+    entity_name: String,
+    view_model: ActixAdminViewModelSerializable,
+    entities: Vec<ActixAdminModel>,
+    has_more: bool,
+}
+
+/// Returns the first entities matching the search, or None if the search contains terms the entity cannot apply
+pub(crate) fn search_entity<'a, E: ActixAdminViewModelTrait>(
+    db: &'a DatabaseConnection,
+    search: &'a str,
+    sort_by: &'a str,
+    entities_per_page: u64,
+) -> ActixAdminSearchFuture<'a> {
+    Box::pin(async move {
+        if !E::validate_search(search).is_empty() {
+            return Ok(None);
+        }
+
+        E::list(db, 1, entities_per_page, None, Vec::new(), search, sort_by, &SortOrder::Asc)
+            .await
+            .map(Some)
+    })
+}
+
+pub async fn global_search(
+    session: Session,
+    req: HttpRequest,
+    data: web::Data<ActixAdmin>,
+    db: web::Data<DatabaseConnection>,
+) -> Result<HttpResponse, Error> {
+    let actix_admin = &data.into_inner();
+
+    let mut ctx = Context::new();
+    ctx.insert("entity_names", &actix_admin.entity_names);
+    add_locale_context(&req, &session, actix_admin, &mut ctx);
+    add_auth_context(&session, actix_admin, &mut ctx);
+
+    let params = web::Query::<Params>::from_query(req.query_string()).unwrap();
+    let search = params.search.clone().unwrap_or_default().trim().to_string();
+
+    let mut view_models: Vec<&ActixAdminViewModel> = actix_admin
+        .view_models
+        .values()
+        .filter(|view_model| {
+            view_model.show_search
+                && actix_admin.search_functions.contains_key(&view_model.entity_name)
+                && user_can_access_page(&session, actix_admin, view_model)
+        })
+        .collect();
+    view_models.sort_by(|a, b| a.entity_name.cmp(&b.entity_name));
+
+    let mut errors: Vec<ActixAdminError> = Vec::new();
+    let mut search_groups: Vec<ActixAdminSearchGroup> = Vec::new();
+    if !search.is_empty() {
+        let searches = view_models.iter().map(|view_model| {
+            let search_fn = actix_admin.search_functions[&view_model.entity_name];
+            search_fn(&db, &search, &view_model.primary_key, ENTITIES_PER_SEARCH_GROUP)
+        });
+        let results = join_all(searches).await;
+        let timezone = get_timezone(&session, actix_admin);
+
+        for (view_model, result) in view_models.iter().zip(results) {
+            match result {
+                Ok(Some((mut entities, has_more))) => {
+                    for entity in entities.iter_mut() {
+                        localize_model(view_model.fields, entity, &timezone);
+                    }
+                    // search hits are masked like the list view
+                    replace_regex(view_model, &mut entities);
+                    search_groups.push(ActixAdminSearchGroup {
+                        entity_name: view_model.entity_name.to_string(),
+                        view_model: ActixAdminViewModelSerializable::from((*view_model).clone()),
+                        entities,
+                        has_more,
+                    })
+                }
+                Ok(None) => {}
+                Err(e) => errors.push(e),
+            }
+        }
+    }
+
+    let notifications: Vec<ActixAdminNotification> = errors
+        .into_iter()
+        .map(ActixAdminNotification::from)
+        .collect();
+
+    ctx.insert("notifications", &notifications);
+    ctx.insert("global_search", &true);
+    ctx.insert("search", &search);
+    ctx.insert("search_groups", &search_groups);
+    ctx.insert("render_partial", &req.headers().contains_key("HX-Target"));
+
+    let body = actix_admin
+        .tera
+        .render(&get_template_name(actix_admin, "", "search.html"), &ctx)
+        .map_err(|e| {
+            #[cfg(feature="enable-tracing")]
+            tracing::error!("{}", e);
+            error::ErrorInternalServerError("Template error")
+        })?;
+    Ok(HttpResponse::Ok().content_type("text/html").body(body))
+}
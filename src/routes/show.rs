@@ -6,9 +6,10 @@ use tera::{Context};
 
 use crate::ActixAdminNotification;
 use crate::prelude::*;
+use crate::timezone::{get_timezone, localize_model};
 
 use super::{Params, DEFAULT_ENTITIES_PER_PAGE};
-use super::{ add_auth_context, user_can_access_page, render_unauthorized};
+use super::{ add_auth_context, add_locale_context, get_template_name, render_error, user_can_access_page, render_unauthorized};
 
 pub async fn show<E: ActixAdminViewModelTrait>(
     session: Session, req: HttpRequest, data: web::Data<ActixAdmin>, id: web::Path<i32>, db: web::Data<DatabaseConnection>
@@ -16,32 +17,22 @@ pub async fn show<E: ActixAdminViewModelTrait>(
     let actix_admin = &data.into_inner();
 
     let mut ctx = Context::new();
+    add_locale_context(&req, &session, actix_admin, &mut ctx);
     let entity_name = E::get_entity_name();
     let view_model: &ActixAdminViewModel = actix_admin.view_models.get(&entity_name).unwrap();
     if !user_can_access_page(&session, actix_admin, view_model) {
         return render_unauthorized(&ctx, &actix_admin);
     }
     
-    let mut errors: Vec<crate::ActixAdminError> = Vec::new();
-    let result = E::get_entity(&db, id.into_inner()).await;
-    let model;
-    match result {
-        Ok(res) => {
-            model = res;
-        },
-        Err(e) => {
-            errors.push(e);
-            model = ActixAdminModel::create_empty();
+    let mut model = match E::get_entity(&db, id.into_inner()).await {
+        Ok(model) => model,
+        Err(err) => {
+            add_auth_context(&session, actix_admin, &mut ctx);
+            return render_error(&ctx, actix_admin, &entity_name, &err);
         }
-    }
-
-    let mut http_response_code = match errors.is_empty() {
-        false => HttpResponse::InternalServerError(),
-        true => HttpResponse::Ok()
-    };    
-    let notifications: Vec<ActixAdminNotification> = errors.into_iter()
-        .map(|err| ActixAdminNotification::from(err))
-        .collect();
+    };
+    localize_model(view_model.fields, &mut model, &get_timezone(&session, actix_admin));
+    let notifications: Vec<ActixAdminNotification> = Vec::new();
 
     let params = web::Query::<Params>::from_query(req.query_string()).unwrap();
 
@@ -56,6 +47,7 @@ pub async fn show<E: ActixAdminViewModelTrait>(
 
     ctx.insert("model", &model);
     ctx.insert("view_model", &ActixAdminViewModelSerializable::from(view_model.clone()));
+    ctx.insert("entity_name", &entity_name);
     ctx.insert("base_path", &E::get_base_path(&entity_name));
     ctx.insert("entity_names", &actix_admin.entity_names);
     ctx.insert("notifications", &notifications);
@@ -69,11 +61,11 @@ pub async fn show<E: ActixAdminViewModelTrait>(
     add_auth_context(&session, actix_admin, &mut ctx);
 
     let body = actix_admin.tera
-        .render("show.html", &ctx)
+        .render(&get_template_name(actix_admin, &entity_name, "show.html"), &ctx)
         .map_err(|err| {
             #[cfg(enable_tracing)]
             tracing::error!("{err}");
             error::ErrorInternalServerError(format!("{:?}", err))
         })?;
-    Ok(http_response_code.content_type("text/html").body(body))
+    Ok(HttpResponse::Ok().content_type("text/html").body(body))
 }
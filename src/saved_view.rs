@@ -0,0 +1,186 @@
+use async_trait::async_trait;
+use sea_orm::sea_query::{Alias, ColumnDef, Condition, Expr, Order, Query, Table};
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbErr, ExecResult};
+use serde_derive::Serialize;
+
+use crate::ActixAdminError;
+
+/// A named combination of search, filters, sorting and page size on a list view
+#[derive(Clone, Debug, Serialize)]
+pub struct ActixAdminSavedView {
+    pub id: Option<i32>,
+    pub entity_name: String,
+    pub name: String,
+    pub user_identifier: Option<String>,
+    pub is_shared: bool,
+    pub query_string: String,
+}
+
+/// Storage backend for saved list views
+#[async_trait]
+pub trait ActixAdminSavedViewStoreTrait: Send + Sync {
+    /// Returns the views of the user as well as all views shared by other users
+    async fn get_saved_views(
+        &self,
+        db: &DatabaseConnection,
+        entity_name: &str,
+        user_identifier: Option<&str>,
+    ) -> Result<Vec<ActixAdminSavedView>, ActixAdminError>;
+    async fn save_view(
+        &self,
+        db: &DatabaseConnection,
+        saved_view: ActixAdminSavedView,
+    ) -> Result<(), ActixAdminError>;
+    /// Deletes a view, only if it is owned by the user
+    async fn delete_view(
+        &self,
+        db: &DatabaseConnection,
+        entity_name: &str,
+        id: i32,
+        user_identifier: Option<&str>,
+    ) -> Result<(), ActixAdminError>;
+}
+
+/// Default store persisting the saved views in a table of the admin database
+#[derive(Clone, Debug)]
+pub struct SeaOrmSavedViewStore {
+    pub table_name: String,
+}
+
+impl Default for SeaOrmSavedViewStore {
+    fn default() -> Self {
+        SeaOrmSavedViewStore {
+            table_name: "actix_admin_saved_view".to_string(),
+        }
+    }
+}
+
+impl SeaOrmSavedViewStore {
+    /// Creates the table used by the store if it does not exist yet
+    pub async fn create_table(&self, db: &DatabaseConnection) -> Result<ExecResult, DbErr> {
+        let stmt = Table::create()
+            .table(Alias::new(&self.table_name))
+            .if_not_exists()
+            .col(
+                ColumnDef::new(Alias::new("id"))
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(ColumnDef::new(Alias::new("entity_name")).string().not_null())
+            .col(ColumnDef::new(Alias::new("name")).string().not_null())
+            .col(ColumnDef::new(Alias::new("user_identifier")).string())
+            .col(ColumnDef::new(Alias::new("is_shared")).boolean().not_null())
+            .col(ColumnDef::new(Alias::new("query_string")).text().not_null())
+            .to_owned();
+
+        let builder = db.get_database_backend();
+        db.execute(builder.build(&stmt)).await
+    }
+
+    fn user_condition(user_identifier: Option<&str>) -> Condition {
+        match user_identifier {
+            Some(user) => Condition::all().add(Expr::col(Alias::new("user_identifier")).eq(user)),
+            None => Condition::all().add(Expr::col(Alias::new("user_identifier")).is_null()),
+        }
+    }
+}
+
+#[async_trait]
+impl ActixAdminSavedViewStoreTrait for SeaOrmSavedViewStore {
+    async fn get_saved_views(
+        &self,
+        db: &DatabaseConnection,
+        entity_name: &str,
+        user_identifier: Option<&str>,
+    ) -> Result<Vec<ActixAdminSavedView>, ActixAdminError> {
+        let stmt = Query::select()
+            .columns([
+                Alias::new("id"),
+                Alias::new("entity_name"),
+                Alias::new("name"),
+                Alias::new("user_identifier"),
+                Alias::new("is_shared"),
+                Alias::new("query_string"),
+            ])
+            .from(Alias::new(&self.table_name))
+            .and_where(Expr::col(Alias::new("entity_name")).eq(entity_name))
+            .cond_where(
+                Condition::any()
+                    .add(Expr::col(Alias::new("is_shared")).eq(true))
+                    .add(Self::user_condition(user_identifier)),
+            )
+            .order_by(Alias::new("name"), Order::Asc)
+            .to_owned();
+
+        let builder = db.get_database_backend();
+        let rows = db.query_all(builder.build(&stmt)).await?;
+
+        let mut saved_views = Vec::new();
+        for row in rows {
+            saved_views.push(ActixAdminSavedView {
+                id: Some(row.try_get("", "id")?),
+                entity_name: row.try_get("", "entity_name")?,
+                name: row.try_get("", "name")?,
+                user_identifier: row.try_get("", "user_identifier")?,
+                is_shared: row.try_get("", "is_shared")?,
+                query_string: row.try_get("", "query_string")?,
+            });
+        }
+
+        Ok(saved_views)
+    }
+
+    async fn save_view(
+        &self,
+        db: &DatabaseConnection,
+        saved_view: ActixAdminSavedView,
+    ) -> Result<(), ActixAdminError> {
+        let stmt = Query::insert()
+            .into_table(Alias::new(&self.table_name))
+            .columns([
+                Alias::new("entity_name"),
+                Alias::new("name"),
+                Alias::new("user_identifier"),
+                Alias::new("is_shared"),
+                Alias::new("query_string"),
+            ])
+            .values_panic([
+                saved_view.entity_name.into(),
+                saved_view.name.into(),
+                saved_view.user_identifier.into(),
+                saved_view.is_shared.into(),
+                saved_view.query_string.into(),
+            ])
+            .to_owned();
+
+        let builder = db.get_database_backend();
+        db.execute(builder.build(&stmt)).await?;
+
+        Ok(())
+    }
+
+    async fn delete_view(
+        &self,
+        db: &DatabaseConnection,
+        entity_name: &str,
+        id: i32,
+        user_identifier: Option<&str>,
+    ) -> Result<(), ActixAdminError> {
+        let stmt = Query::delete()
+            .from_table(Alias::new(&self.table_name))
+            .and_where(Expr::col(Alias::new("entity_name")).eq(entity_name))
+            .and_where(Expr::col(Alias::new("id")).eq(id))
+            .cond_where(Self::user_condition(user_identifier))
+            .to_owned();
+
+        let builder = db.get_database_backend();
+        let result = db.execute(builder.build(&stmt)).await?;
+
+        match result.rows_affected() {
+            0 => Err(ActixAdminError::EntityDoesNotExistError),
+            _ => Ok(()),
+        }
+    }
+}
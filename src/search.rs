@@ -0,0 +1,283 @@
+use chrono::{Duration, NaiveDate, NaiveDateTime};
+use futures_util::future::LocalBoxFuture;
+use lazy_static::lazy_static;
+use regex::Regex;
+use sea_orm::sea_query::Condition;
+use sea_orm::{ColumnTrait, DatabaseConnection, Value};
+use serde_derive::Serialize;
+
+use crate::{ActixAdminError, ActixAdminModel};
+
+lazy_static! {
+    static ref FIELD_TERM_REGEX: Regex =
+        Regex::new(r"^([A-Za-z_][A-Za-z0-9_]*)(>=|<=|:|=|>|<)(.*)$").unwrap();
+}
+
+/// Searches the entities of one type for the global search with the search string, the column to sort by
+/// and the maximum number of entities. Returns None if the search contains terms which cannot be applied to the entity.
+pub type ActixAdminSearchFn = for<'a> fn(&'a DatabaseConnection, &'a str, &'a str, u64) -> ActixAdminSearchFuture<'a>;
+pub type ActixAdminSearchFuture<'a> = LocalBoxFuture<'a, Result<Option<(Vec<ActixAdminModel>, bool)>, ActixAdminError>>;
+
+/// Comparison of a field-qualified search term, e.g. `insert_date>2023-01-01`
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub enum ActixAdminSearchOperator {
+    /// `field:value`, a substring for text fields, the value itself for all other types
+    Contains,
+    /// `field=value`
+    Equal,
+    /// `field>value`
+    Greater,
+    /// `field>=value`
+    GreaterOrEqual,
+    /// `field<value`
+    Less,
+    /// `field<=value`
+    LessOrEqual,
+}
+
+impl ActixAdminSearchOperator {
+    fn from_str(operator: &str) -> Self {
+        match operator {
+            "=" => ActixAdminSearchOperator::Equal,
+            ">" => ActixAdminSearchOperator::Greater,
+            ">=" => ActixAdminSearchOperator::GreaterOrEqual,
+            "<" => ActixAdminSearchOperator::Less,
+            "<=" => ActixAdminSearchOperator::LessOrEqual,
+            _ => ActixAdminSearchOperator::Contains,
+        }
+    }
+}
+
+/// A single term of the search box. Terms without field are matched against the searchable fields.
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct ActixAdminSearchTerm {
+    pub raw: String,
+    pub field_name: Option<String>,
+    pub operator: ActixAdminSearchOperator,
+    pub value: String,
+    pub is_negated: bool,
+}
+
+impl ActixAdminSearchTerm {
+    /// Turns a field-qualified term into a plain text term, which is searched as a whole
+    pub fn into_plain_text(self) -> Self {
+        if self.field_name.is_none() {
+            return self;
+        }
+
+        let token = match self.is_negated {
+            true => &self.raw[1..],
+            false => self.raw.as_str(),
+        };
+        ActixAdminSearchTerm {
+            field_name: None,
+            operator: ActixAdminSearchOperator::Contains,
+            value: unquote(token),
+            ..self
+        }
+    }
+}
+
+fn split_search(search: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut token = String::new();
+    let mut is_quoted = false;
+
+    for c in search.chars() {
+        match c {
+            '"' => {
+                is_quoted = !is_quoted;
+                token.push(c);
+            }
+            c if c.is_whitespace() && !is_quoted => {
+                if !token.is_empty() {
+                    tokens.push(std::mem::take(&mut token));
+                }
+            }
+            c => token.push(c),
+        }
+    }
+    if !token.is_empty() {
+        tokens.push(token);
+    }
+
+    tokens
+}
+
+fn unquote(value: &str) -> String {
+    value.trim_matches('"').to_string()
+}
+
+fn is_plain_word(token: &str) -> bool {
+    !token.starts_with('-') && !token.starts_with('"') && !FIELD_TERM_REGEX.is_match(token)
+}
+
+/// Splits the search string into its terms, e.g. `user:alice insert_date>2023-01-01 -spam "exact phrase"`.
+/// Consecutive plain words are kept together as one phrase, so a search without syntax behaves as before.
+/// Terms without a value are returned as error.
+pub fn parse_search_terms(search: &str) -> Vec<Result<ActixAdminSearchTerm, String>> {
+    let mut tokens: Vec<String> = Vec::new();
+    for token in split_search(search) {
+        match tokens.last_mut() {
+            Some(last) if is_plain_word(last) && is_plain_word(&token) => {
+                last.push(' ');
+                last.push_str(&token);
+            }
+            _ => tokens.push(token),
+        }
+    }
+
+    tokens
+        .into_iter()
+        .map(|raw| {
+            let (is_negated, token) = match raw.strip_prefix('-') {
+                Some(token) if !token.is_empty() => (true, token),
+                _ => (false, raw.as_str()),
+            };
+
+            let term = match FIELD_TERM_REGEX.captures(token) {
+                Some(captures) => ActixAdminSearchTerm {
+                    raw: raw.clone(),
+                    field_name: Some(captures[1].to_string()),
+                    operator: ActixAdminSearchOperator::from_str(&captures[2]),
+                    value: unquote(&captures[3]),
+                    is_negated,
+                },
+                None => ActixAdminSearchTerm {
+                    raw: raw.clone(),
+                    field_name: None,
+                    operator: ActixAdminSearchOperator::Contains,
+                    value: unquote(token),
+                    is_negated,
+                },
+            };
+
+            match term.value.is_empty() {
+                true => Err(format!("Invalid search term {}: missing value", raw)),
+                false => Ok(term),
+            }
+        })
+        .collect()
+}
+
+/// Types which can be compared with the value of a field-qualified search term
+pub trait ActixAdminSearchValue: Sized {
+    fn from_search_value(value: &str) -> Result<Self, String>;
+
+    fn is_text() -> bool {
+        false
+    }
+
+    fn is_ordered() -> bool {
+        true
+    }
+
+    /// Start (inclusive) and end (exclusive) of the period a value stands for,
+    /// e.g. a whole day for a date time which is searched by its date
+    fn get_search_range(_value: &str) -> Option<(Self, Self)> {
+        None
+    }
+}
+
+macro_rules! impl_search_value_for_number {
+    ($($ty:ty),*) => {
+        $(
+            impl ActixAdminSearchValue for $ty {
+                fn from_search_value(value: &str) -> Result<Self, String> {
+                    value.parse::<$ty>().map_err(|_| format!("{} is not a valid number", value))
+                }
+            }
+        )*
+    };
+}
+
+impl_search_value_for_number!(i8, i16, i32, i64, u8, u16, u32, u64, f32, f64);
+
+impl ActixAdminSearchValue for String {
+    fn from_search_value(value: &str) -> Result<Self, String> {
+        Ok(value.to_string())
+    }
+
+    fn is_text() -> bool {
+        true
+    }
+}
+
+impl ActixAdminSearchValue for bool {
+    fn from_search_value(value: &str) -> Result<Self, String> {
+        match value.to_lowercase().as_str() {
+            "true" | "yes" | "1" => Ok(true),
+            "false" | "no" | "0" => Ok(false),
+            _ => Err(format!("{} is not a valid boolean", value)),
+        }
+    }
+
+    fn is_ordered() -> bool {
+        false
+    }
+}
+
+impl ActixAdminSearchValue for NaiveDate {
+    fn from_search_value(value: &str) -> Result<Self, String> {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .map_err(|_| format!("{} is not a valid date (YYYY-MM-DD)", value))
+    }
+}
+
+impl ActixAdminSearchValue for NaiveDateTime {
+    fn from_search_value(value: &str) -> Result<Self, String> {
+        NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S")
+            .or_else(|_| NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M"))
+            .or_else(|_| NaiveDate::from_search_value(value).map(|date| date.and_hms_opt(0, 0, 0).unwrap()))
+            .map_err(|_| format!("{} is not a valid date (YYYY-MM-DD or YYYY-MM-DDTHH:MM)", value))
+    }
+
+    fn get_search_range(value: &str) -> Option<(Self, Self)> {
+        NaiveDate::from_search_value(value).ok().map(|date| {
+            let start = date.and_hms_opt(0, 0, 0).unwrap();
+            (start, start + Duration::days(1))
+        })
+    }
+}
+
+/// Builds the condition of a field-qualified search term for a column holding values of type V.
+/// Negation is left to the caller.
+pub fn get_search_term_condition<C, V>(column: C, term: &ActixAdminSearchTerm) -> Result<Condition, String>
+where
+    C: ColumnTrait,
+    V: ActixAdminSearchValue + Into<Value>,
+{
+    let invalid_term = |msg: String| format!("Invalid search term {}: {}", term.raw, msg);
+
+    if !V::is_ordered() && !matches!(term.operator, ActixAdminSearchOperator::Contains | ActixAdminSearchOperator::Equal) {
+        return Err(invalid_term("the field cannot be compared with < or >".to_string()));
+    }
+
+    if V::is_text() && term.operator == ActixAdminSearchOperator::Contains {
+        return Ok(Condition::all().add(column.contains(&term.value)));
+    }
+
+    if let Some((start, end)) = V::get_search_range(&term.value) {
+        let condition = match term.operator {
+            ActixAdminSearchOperator::Contains | ActixAdminSearchOperator::Equal => {
+                Condition::all().add(column.gte(start)).add(column.lt(end))
+            }
+            ActixAdminSearchOperator::Greater => Condition::all().add(column.gte(end)),
+            ActixAdminSearchOperator::GreaterOrEqual => Condition::all().add(column.gte(start)),
+            ActixAdminSearchOperator::Less => Condition::all().add(column.lt(start)),
+            ActixAdminSearchOperator::LessOrEqual => Condition::all().add(column.lt(end)),
+        };
+        return Ok(condition);
+    }
+
+    let value = V::from_search_value(&term.value).map_err(invalid_term)?;
+    let expr = match term.operator {
+        ActixAdminSearchOperator::Contains | ActixAdminSearchOperator::Equal => column.eq(value),
+        ActixAdminSearchOperator::Greater => column.gt(value),
+        ActixAdminSearchOperator::GreaterOrEqual => column.gte(value),
+        ActixAdminSearchOperator::Less => column.lt(value),
+        ActixAdminSearchOperator::LessOrEqual => column.lte(value),
+    };
+
+    Ok(Condition::all().add(expr))
+}
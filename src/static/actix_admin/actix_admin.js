@@ -0,0 +1,119 @@
+document.onkeydown = function (e) {
+    switch (e.which) {
+        case 37: // left
+            let left_el = document.getElementsByClassName('left-arrow-click').item(0);
+            if (left_el) { left_el.click(); };
+            break;
+
+        //case 38: // up
+        //    break;
+
+        case 39: // right
+            let right_el = document.getElementsByClassName('right-arrow-click').item(0);
+            if (right_el) { right_el.click(); };
+            break;
+
+        //case 40: // down
+        //    break;
+
+        default: return; // exit this handler for other keys
+    }
+    e.preventDefault(); // prevent the default action (scroll / move caret)
+};
+
+function checkAll(bx) {
+    var cbs = document.getElementsByTagName('input');
+    for (var i = 0; i < cbs.length; i++) {
+        if (cbs[i].type == 'checkbox') {
+            cbs[i].checked = bx.checked;
+        }
+    }
+}
+
+function sort_by(column) {
+    console.log(column);
+    current_sort_order = document.getElementsByName("sort_order")[0].value;
+    if (current_sort_order == "Asc") {
+        document.getElementsByName("sort_order").forEach((e) => e.value = "Desc");
+    } else {
+        document.getElementsByName("sort_order").forEach((e) => e.value = "Asc");
+    }
+    document.getElementsByName("sort_by").forEach((e) => e.value = column);
+    document.getElementById('table_form').requestSubmit();
+}
+
+function toggle_aside() {
+    el = document.getElementById("nav_aside");
+    if(el.classList.contains("is-hidden")) {
+        el.classList.remove("is-hidden");
+    } else {
+        el.classList.add("is-hidden");
+    }
+}
+
+document.addEventListener('DOMContentLoaded', () => {
+    // Get all "navbar-burger" elements
+    const $navbarBurgers = Array.prototype.slice.call(document.querySelectorAll('.navbar-burger'), 0);
+
+    // Add a click event on each of them
+    $navbarBurgers.forEach(el => {
+        el.addEventListener('click', () => {
+
+            // Get the target from the "data-target" attribute
+            const target = el.dataset.target;
+            const $target = document.getElementById(target);
+
+            // Toggle the "is-active" class on both the "navbar-burger" and the "navbar-menu"
+            el.classList.toggle('is-active');
+            $target.classList.toggle('is-active');
+
+        });
+    });
+});
+
+function show_error() {
+    let notifications = document.getElementById("notifications");
+    let error = document.createElement("div");
+    error.className = "notification mb-4 is-light is-danger";
+    error.textContent = notifications.dataset.errorMessage;
+    let button = document.createElement("button");
+    button.className = "delete";
+    button.addEventListener("click", () => error.remove());
+    error.prepend(button);
+    notifications.insertAdjacentElement("afterend", error);
+}
+
+htmx.on("htmx:responseError", show_error);
+htmx.on("htmx:sendError", show_error);
+
+// forms with validation errors, conflicting edits and missing entities are rendered with 422, 409 and 404, which htmx does not swap by default.
+// failed bulk deletes are retargeted to the notifications with any status code.
+htmx.on("htmx:beforeSwap", function (e) {
+    if ([404, 409, 422].includes(e.detail.xhr.status) || e.detail.xhr.getResponseHeader("HX-Retarget") === "#notifications") {
+        e.detail.shouldSwap = true;
+        e.detail.isError = false;
+    }
+});
+
+// json fields are edited in a textarea which indents with the tab key and is formatted when it loses the focus
+document.addEventListener("keydown", function (e) {
+    let editor = e.target;
+    if (e.key !== "Tab" || !editor.matches("textarea[data-json-editor]")) {
+        return;
+    }
+    e.preventDefault();
+    editor.setRangeText("  ", editor.selectionStart, editor.selectionEnd, "end");
+});
+
+document.addEventListener("focusout", function (e) {
+    let editor = e.target;
+    if (!editor.matches("textarea[data-json-editor]") || editor.value.trim() === "") {
+        return;
+    }
+    try {
+        editor.value = JSON.stringify(JSON.parse(editor.value), null, 2);
+        editor.classList.remove("is-danger");
+    } catch (err) {
+        editor.classList.add("is-danger");
+    }
+});
@@ -0,0 +1,60 @@
+// Versions and files of the frontend libraries, included by src/assets.rs and read by
+// scripts/vendor_assets.sh so that a version is only defined here.
+
+macro_rules! bulma_version {
+    () => {
+        "0.9.4"
+    };
+}
+
+macro_rules! font_awesome_version {
+    () => {
+        "6.4.0"
+    };
+}
+
+macro_rules! htmx_version {
+    () => {
+        "1.9.2"
+    };
+}
+
+macro_rules! bulma_cdn_url {
+    () => {
+        concat!("https://cdn.jsdelivr.net/npm/bulma@", bulma_version!())
+    };
+}
+
+macro_rules! font_awesome_cdn_url {
+    () => {
+        concat!("https://cdnjs.cloudflare.com/ajax/libs/font-awesome/", font_awesome_version!())
+    };
+}
+
+macro_rules! htmx_cdn_url {
+    () => {
+        concat!("https://unpkg.com/htmx.org@", htmx_version!(), "/dist")
+    };
+}
+
+/// Calls the macro with the files embedded by the feature `embedded-assets` as
+/// (library, version, file, mime type). The files are vendored in `src/static/library/version/file`
+/// and served below the static route under the same path.
+#[allow(unused_macros)]
+macro_rules! with_library_files {
+    ($callback:ident) => {
+        $callback!(
+            ("bulma", bulma_version!(), "/css/bulma.min.css", "text/css"),
+            ("font-awesome", font_awesome_version!(), "/css/all.min.css", "text/css"),
+            ("font-awesome", font_awesome_version!(), "/webfonts/fa-brands-400.woff2", "font/woff2"),
+            ("font-awesome", font_awesome_version!(), "/webfonts/fa-brands-400.ttf", "font/ttf"),
+            ("font-awesome", font_awesome_version!(), "/webfonts/fa-regular-400.woff2", "font/woff2"),
+            ("font-awesome", font_awesome_version!(), "/webfonts/fa-regular-400.ttf", "font/ttf"),
+            ("font-awesome", font_awesome_version!(), "/webfonts/fa-solid-900.woff2", "font/woff2"),
+            ("font-awesome", font_awesome_version!(), "/webfonts/fa-solid-900.ttf", "font/ttf"),
+            ("font-awesome", font_awesome_version!(), "/webfonts/fa-v4compatibility.woff2", "font/woff2"),
+            ("font-awesome", font_awesome_version!(), "/webfonts/fa-v4compatibility.ttf", "font/ttf"),
+            ("htmx.org", htmx_version!(), "/htmx.min.js", "application/javascript")
+        )
+    };
+}
@@ -0,0 +1,35 @@
+use serde_derive::Serialize;
+
+/// Colour scheme of the admin interface, `Auto` follows the preference of the browser
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ActixAdminThemeMode {
+    #[default]
+    Light,
+    Dark,
+    Auto,
+}
+
+/// Environment which is shown as badge in the navbar, e.g. to distinguish staging from production
+#[derive(Clone, Debug, Serialize)]
+pub struct ActixAdminEnvironment {
+    pub name: String,
+    /// CSS colour of the badge and the top border of the navbar
+    pub color: String,
+}
+
+/// Appearance of the admin interface, applied through CSS variables in the built-in templates
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ActixAdminTheme {
+    pub mode: ActixAdminThemeMode,
+    /// CSS colour of primary buttons, links and active elements
+    pub primary_color: Option<String>,
+    /// CSS colour of the navbar background
+    pub navbar_color: Option<String>,
+    /// CSS colour of the navbar text
+    pub navbar_text_color: Option<String>,
+    /// URL of an image which is shown in the navbar before the title
+    pub logo_url: Option<String>,
+    pub favicon_url: Option<String>,
+    pub environment: Option<ActixAdminEnvironment>,
+}
@@ -0,0 +1,100 @@
+use actix_session::Session;
+use chrono::{DateTime, FixedOffset, NaiveDateTime, Offset, TimeZone};
+use chrono_tz::Tz;
+
+use crate::view_model::{ActixAdminViewModelField, ActixAdminViewModelFieldType, ActixAdminViewModelFilter};
+use crate::{ActixAdmin, ActixAdminModel};
+
+/// Session key holding the IANA name of the timezone chosen by the user, e.g. `Europe/Zurich`
+pub const TIMEZONE_SESSION_KEY: &str = "actix_admin_timezone";
+
+/// Formats of datetime-local inputs, which omit the seconds if they are zero
+const NAIVE_DATETIME_FORMATS: [&str; 4] = ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%dT%H:%M", "%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%d %H:%M"];
+
+/// Chooses the timezone of a request: the timezone stored in the session or the default timezone of the configuration
+pub fn get_timezone(session: &Session, actix_admin: &ActixAdmin) -> Tz {
+    session
+        .get::<String>(TIMEZONE_SESSION_KEY)
+        .unwrap_or(None)
+        .and_then(|timezone| timezone.parse::<Tz>().ok())
+        .unwrap_or(actix_admin.configuration.default_timezone)
+}
+
+/// Parses the value of a datetime-local input with or without seconds
+pub fn parse_naive_datetime(value: &str) -> Option<NaiveDateTime> {
+    NAIVE_DATETIME_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+}
+
+/// Parses a datetime with offset in RFC 3339 or in the format of its `Display` implementation
+pub fn parse_datetime_with_timezone(value: &str) -> Option<DateTime<FixedOffset>> {
+    DateTime::parse_from_rfc3339(value)
+        .or_else(|_| DateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S%.f %:z"))
+        .ok()
+}
+
+/// Parses the value of a datetime filter of a timezone-aware field, values without offset are local datetimes in the timezone
+pub fn parse_filter_datetime_with_timezone(value: &str, timezone: &Tz) -> Option<DateTime<FixedOffset>> {
+    parse_datetime_with_timezone(value).or_else(|| parse_local_datetime(value, timezone))
+}
+
+fn parse_local_datetime(value: &str, timezone: &Tz) -> Option<DateTime<FixedOffset>> {
+    let datetime = timezone.from_local_datetime(&parse_naive_datetime(value)?).earliest()?;
+    Some(datetime.with_timezone(&datetime.offset().fix()))
+}
+
+/// Converts a datetime with offset to the local datetime in the timezone
+pub fn to_local_datetime(value: &str, timezone: &Tz) -> Option<String> {
+    parse_datetime_with_timezone(value).map(|datetime| datetime.with_timezone(timezone).naive_local().to_string())
+}
+
+/// Converts a local datetime in the timezone to a datetime with offset in RFC 3339. Ambiguous local
+/// datetimes at the end of daylight saving time resolve to the earlier one.
+pub fn from_local_datetime(value: &str, timezone: &Tz) -> Option<String> {
+    parse_local_datetime(value, timezone).map(|datetime| datetime.to_rfc3339())
+}
+
+fn convert_datetimes_with_timezone(fields: &[ActixAdminViewModelField], model: &mut ActixAdminModel, convert: impl Fn(&str) -> Option<String>) {
+    for field in fields.iter().filter(|field| field.field_type == ActixAdminViewModelFieldType::DateTimeWithTimeZone) {
+        if let Some(value) = model.values.get_mut(&field.field_name) {
+            // invalid values are left unchanged to be reported by the validation
+            if let Some(converted) = convert(value) {
+                *value = converted;
+            }
+        }
+    }
+}
+
+/// Converts the values of timezone-aware fields to local datetimes in the timezone of the user
+pub fn localize_model(fields: &[ActixAdminViewModelField], model: &mut ActixAdminModel, timezone: &Tz) {
+    convert_datetimes_with_timezone(fields, model, |value| to_local_datetime(value, timezone));
+}
+
+/// Converts the values of filters of timezone-aware fields, including the bounds `_from` and `_to` of
+/// range filters, from local datetimes in the timezone of the user to datetimes with offset
+pub fn delocalize_filters(fields: &[ActixAdminViewModelField], filters: &mut [ActixAdminViewModelFilter], timezone: &Tz) {
+    let is_timezone_aware = |name: &str| {
+        fields.iter().any(|field| field.field_name == name && field.field_type == ActixAdminViewModelFieldType::DateTimeWithTimeZone)
+    };
+    for filter in filters.iter_mut() {
+        let name = filter.name.as_str();
+        if !(is_timezone_aware(name)
+            || name.strip_suffix("_from").is_some_and(is_timezone_aware)
+            || name.strip_suffix("_to").is_some_and(is_timezone_aware))
+        {
+            continue;
+        }
+        // invalid values are left unchanged and ignored by the filter query
+        if let Some(value) = filter.value.as_mut() {
+            if let Some(datetime) = parse_filter_datetime_with_timezone(value, timezone) {
+                *value = datetime.to_rfc3339();
+            }
+        }
+    }
+}
+
+/// Converts the local datetimes of timezone-aware fields in a submitted form to datetimes with offset
+pub fn delocalize_model(fields: &[ActixAdminViewModelField], model: &mut ActixAdminModel, timezone: &Tz) {
+    convert_datetimes_with_timezone(fields, model, |value| from_local_datetime(value, timezone));
+}
@@ -3,34 +3,57 @@ use regex::Regex;
 use sea_orm::DatabaseConnection;
 use serde_derive::{Serialize, Deserialize};
 use std::collections::HashMap;
-use crate::{ActixAdminModel, SortOrder, model::ActixAdminModelFilterType};
+use crate::{ActixAdminModel, SortOrder, model::ActixAdminModelFilterType, model::ActixAdminValidationMode};
+use crate::pagination::{ActixAdminCountType, ActixAdminCursor, ActixAdminPaginationType};
 use actix_session::{Session};
 use std::convert::From;
 use crate::ActixAdminError;
 
 #[async_trait(?Send)]
 pub trait ActixAdminViewModelTrait {
+    #[allow(clippy::too_many_arguments)]
     async fn list(
         db: &DatabaseConnection,
         page: u64,
         entities_per_page: u64,
+        cursor: Option<ActixAdminCursor>,
         viewmodel_filter: Vec<ActixAdminViewModelFilter>,
         search: &str,
         sort_by: &str,
         sort_order: &SortOrder
-    ) -> Result<(u64, Vec<ActixAdminModel>), ActixAdminError>;
+    ) -> Result<(Vec<ActixAdminModel>, bool), ActixAdminError>;
+    async fn count(
+        db: &DatabaseConnection,
+        viewmodel_filter: Vec<ActixAdminViewModelFilter>,
+        search: &str
+    ) -> Result<u64, ActixAdminError>;
+    /// Returns a message for each term of the search which cannot be applied
+    fn validate_search(search: &str) -> Vec<String>;
     
     // TODO: Replace return value with proper Result Type containing Ok or Err
     async fn create_entity(db: &DatabaseConnection, model: ActixAdminModel) -> Result<ActixAdminModel, ActixAdminError>;
     async fn delete_entity(db: &DatabaseConnection, id: i32) -> Result<bool, ActixAdminError>;
+    /// Deletes the entities with a single query in one transaction and returns the deleted entities.
+    /// Missing ids and errors of the delete hooks are returned as [ActixAdminError::DeleteManyError].
+    async fn delete_entities(db: &DatabaseConnection, ids: &[i32]) -> Result<Vec<ActixAdminModel>, ActixAdminError>;
     async fn get_entity(db: &DatabaseConnection, id: i32) -> Result<ActixAdminModel, ActixAdminError>;
     async fn edit_entity(db: &DatabaseConnection, id: i32, model: ActixAdminModel) -> Result<ActixAdminModel, ActixAdminError>;
     async fn get_select_lists(db: &DatabaseConnection) -> Result<HashMap<String, Vec<(String, String)>>, ActixAdminError>;
     async fn get_viewmodel_filter(db: &DatabaseConnection) -> HashMap<String, ActixAdminViewModelFilter>;
     fn validate_entity(model: &mut ActixAdminModel);
+    /// Runs the async validation of the entity, see [validate_async](crate::model::ActixAdminModelValidationTrait::validate_async)
+    async fn validate_entity_async(
+        db: &DatabaseConnection,
+        session: &Session,
+        mode: &ActixAdminValidationMode,
+        model: &mut ActixAdminModel
+    ) -> Result<(), ActixAdminError>;
 
     fn get_entity_name() -> String;
 
+    /// Name of the table of the entity in the database
+    fn get_table_name() -> String;
+
     fn get_base_path(entity_name: &String) -> String {
         format!("/admin/{}", entity_name)
     }
@@ -43,7 +66,11 @@ pub struct ActixAdminViewModel {
     pub fields: &'static[ActixAdminViewModelField],
     pub show_search: bool,
     pub user_can_access: Option<fn(&Session) -> bool>,
-    pub default_show_aside: bool
+    pub default_show_aside: bool,
+    pub pagination_type: ActixAdminPaginationType,
+    pub count_type: ActixAdminCountType,
+    /// Field for optimistic locking, declared with `#[actix_admin(version)]`
+    pub version_field: Option<String>
 }
 
 #[derive(Clone, Debug, Serialize)]
@@ -52,13 +79,18 @@ pub struct ActixAdminViewModelSerializable {
     pub primary_key: String,
     pub fields: &'static [ActixAdminViewModelField],
     pub show_search: bool,
-    pub default_show_aside: bool
+    pub default_show_aside: bool,
+    pub pagination_type: ActixAdminPaginationType,
+    pub count_type: ActixAdminCountType,
+    pub version_field: Option<String>
 }
 
 #[derive(Clone, Debug, Serialize)]
 pub struct ActixAdminViewModelFilter {
     pub name: String,
     pub value: Option<String>,
+    /// Upper bound of a range filter, whose lower bound is the value
+    pub value_to: Option<String>,
     pub values: Option<Vec<(String, String)>>,
     pub filter_type: Option<ActixAdminModelFilterType>
 }
@@ -71,7 +103,10 @@ impl From<ActixAdminViewModel> for ActixAdminViewModelSerializable {
             primary_key: entity.primary_key,
             fields: entity.fields,
             show_search: entity.show_search,
-            default_show_aside: entity.default_show_aside
+            default_show_aside: entity.default_show_aside,
+            pagination_type: entity.pagination_type,
+            count_type: entity.count_type,
+            version_field: entity.version_field
         }
     }
 }
@@ -85,8 +120,11 @@ pub enum ActixAdminViewModelFieldType {
     Date,
     Time,
     DateTime,
+    /// Datetime with offset, which is shown and entered in the timezone of the user
+    DateTimeWithTimeZone,
     SelectList,
-    FileUpload
+    FileUpload,
+    Json
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -99,7 +137,58 @@ pub struct ActixAdminViewModelField {
     pub list_sort_position: usize,
     pub list_hide_column: bool,
     #[serde(skip_serializing, skip_deserializing)]
-    pub list_regex_mask: Option<Regex>
+    pub list_regex_mask: Option<Regex>,
+    pub label: Option<String>,
+    pub help_text: Option<String>,
+    pub placeholder: Option<String>,
+    /// The field can only be set when the entity is created and is shown disabled in the edit form
+    pub readonly_after_create: bool,
+    /// The field is not part of the create and edit forms
+    pub form_hidden: bool,
+    /// The field is a computed column, see [ActixAdminComputedColumn](crate::computed::ActixAdminComputedColumn)
+    pub is_computed: bool,
+    pub is_sortable: bool,
+    /// Name of the renderer registered with [add_renderer](crate::builder::ActixAdminBuilderTrait::add_renderer)
+    pub renderer: Option<String>,
+    /// Step and bounds of the input of numeric fields
+    pub number_input: Option<ActixAdminNumberInput>
+}
+
+/// Attributes of the number input of a field, derived from its type
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct ActixAdminNumberInput {
+    /// `1` for integers, `any` for floats and decimals
+    pub step: String,
+    pub min: Option<String>,
+    pub max: Option<String>,
+}
+
+impl ActixAdminNumberInput {
+    fn integer(min: impl ToString, max: impl ToString) -> Self {
+        ActixAdminNumberInput { step: "1".to_string(), min: Some(min.to_string()), max: Some(max.to_string()) }
+    }
+
+    fn decimal() -> Self {
+        ActixAdminNumberInput { step: "any".to_string(), min: None, max: None }
+    }
+
+    /// Returns the attributes of the number input for the numeric types supported by sea-orm
+    pub fn from_type_path(type_path: &str) -> Option<Self> {
+        match type_path {
+            "i8" => Some(Self::integer(i8::MIN, i8::MAX)),
+            "i16" => Some(Self::integer(i16::MIN, i16::MAX)),
+            "i32" => Some(Self::integer(i32::MIN, i32::MAX)),
+            "i64" => Some(Self::integer(i64::MIN, i64::MAX)),
+            "isize" => Some(Self::integer(isize::MIN, isize::MAX)),
+            "u8" => Some(Self::integer(u8::MIN, u8::MAX)),
+            "u16" => Some(Self::integer(u16::MIN, u16::MAX)),
+            "u32" => Some(Self::integer(u32::MIN, u32::MAX)),
+            "u64" => Some(Self::integer(u64::MIN, u64::MAX)),
+            "usize" => Some(Self::integer(usize::MIN, usize::MAX)),
+            "f32" | "f64" | "Decimal" | "rust_decimal :: Decimal" => Some(Self::decimal()),
+            _ => None
+        }
+    }
 }
 
 impl ActixAdminViewModelFieldType {
@@ -115,14 +204,14 @@ impl ActixAdminViewModelFieldType {
         }
 
         match type_path {
-            "i32" => ActixAdminViewModelFieldType::Number,
-            "i64" => ActixAdminViewModelFieldType::Number,
-            "usize" => ActixAdminViewModelFieldType::Number,
+            _ if ActixAdminNumberInput::from_type_path(type_path).is_some() => ActixAdminViewModelFieldType::Number,
             "String"  => ActixAdminViewModelFieldType::Text,
             "bool"  => ActixAdminViewModelFieldType::Checkbox,
-            "DateTimeWithTimeZone" => ActixAdminViewModelFieldType::DateTime,
+            "DateTimeWithTimeZone" => ActixAdminViewModelFieldType::DateTimeWithTimeZone,
             "DateTime" => ActixAdminViewModelFieldType::DateTime,
             "Date" => ActixAdminViewModelFieldType::Date,
+            "Time" | "NaiveTime" | "chrono :: NaiveTime" => ActixAdminViewModelFieldType::Time,
+            "Json" | "JsonValue" | "serde_json :: Value" => ActixAdminViewModelFieldType::Json,
             _      => ActixAdminViewModelFieldType::Text
         }
     }
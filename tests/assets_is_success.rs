@@ -0,0 +1,77 @@
+mod test_setup;
+use test_setup::prelude::*;
+
+#[cfg(test)]
+mod assets_is_success {
+    use actix_admin::assets::{get_admin_assets_path, get_asset_urls};
+    use actix_admin::prelude::*;
+    use actix_web::body::to_bytes;
+    use actix_web::http::header::CACHE_CONTROL;
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use actix_web::App;
+    use super::create_app;
+    use super::BodyTest;
+
+    #[actix_web::test]
+    async fn admin_assets_are_served_with_cache_headers() {
+        let db = super::setup_db(false).await;
+        let app = create_app!(db);
+
+        let req = test::TestRequest::get()
+            .uri(&format!("{}/actix_admin.js", get_admin_assets_path()))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_success());
+        assert_eq!(resp.headers().get("content-type").unwrap(), "application/javascript");
+        assert_eq!(resp.headers().get(CACHE_CONTROL).unwrap(), "public, max-age=31536000, immutable");
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        assert!(body.as_str().contains("function sort_by"));
+
+        let req = test::TestRequest::get()
+            .uri("/admin/static/does_not_exist.js")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[cfg(feature = "embedded-assets")]
+    #[actix_web::test]
+    async fn embedded_libraries_are_served() {
+        let db = super::setup_db(false).await;
+        let app = create_app!(db);
+
+        for url in [ActixAdminAssetUrls::embedded().bulma_css, ActixAdminAssetUrls::embedded().htmx_js] {
+            let req = test::TestRequest::get().uri(&url).to_request();
+            let resp = test::call_service(&app, req).await;
+            assert!(resp.status().is_success(), "{} is not embedded", url);
+        }
+    }
+
+    #[actix_web::test]
+    async fn head_loads_configured_assets() {
+        let db = super::setup_db(false).await;
+        let app = create_app!(db);
+
+        let req = test::TestRequest::get().uri("/admin/").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_success());
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        let body = body.as_str();
+        assert!(body.contains("bulma.min.css"));
+        assert!(body.contains("actix_admin.js"));
+        assert!(body.contains("data-error-message=\"An Error occurred\""));
+    }
+
+    #[actix_web::test]
+    async fn configured_asset_urls_are_used() {
+        let mut configuration = super::create_actix_admin_builder().get_actix_admin().configuration;
+        configuration.asset_urls = Some(ActixAdminAssetUrls {
+            bulma_css: "/assets/bulma.css".to_string(),
+            font_awesome_css: "/assets/font-awesome.css".to_string(),
+            htmx_js: "/assets/htmx.js".to_string(),
+        });
+
+        assert_eq!(get_asset_urls(&configuration).htmx_js, "/assets/htmx.js");
+    }
+}
@@ -0,0 +1,76 @@
+mod test_setup;
+use test_setup::prelude::*;
+
+#[cfg(test)]
+mod computed_column_is_success {
+    use actix_admin::prelude::*;
+    use actix_web::body::to_bytes;
+    use actix_web::test;
+    use actix_web::App;
+    use sea_orm::{ActiveModelTrait, Set};
+    use super::create_app;
+    use super::BodyTest;
+
+    #[actix_web::test]
+    async fn list_shows_computed_columns() {
+        let db = super::setup_db(true).await;
+
+        let entities = crate::Post::list(&db, 1, 10, None, Vec::new(), "", "id", &SortOrder::Asc).await.unwrap().0;
+        let first = entities.first().unwrap();
+        assert_eq!(first.values.get("comment_count").unwrap(), "1");
+        assert_eq!(first.values.get("title_length").unwrap(), "6");
+
+        let app = create_app!(db);
+        let req = test::TestRequest::get()
+            .uri("/admin/post/list")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_success());
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        let body = body.as_str();
+        assert!(body.contains("onclick=\"sort_by('comment_count');\""));
+        assert!(!body.contains("onclick=\"sort_by('title_length');\""));
+        assert!(body.contains("Title Length"));
+    }
+
+    #[actix_web::test]
+    async fn list_sorts_by_computed_expression() {
+        let db = super::setup_db(true).await;
+
+        for _ in 0..2 {
+            super::test_setup::comment::ActiveModel {
+                comment: Set("additional".to_string()),
+                user: Set("additional".to_string()),
+                insert_date: Set(chrono::Local::now().naive_utc()),
+                is_visible: Set(true),
+                post_id: Set(Some(42)),
+                my_decimal: Set(sea_orm::prelude::Decimal::new(105, 0)),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+        }
+
+        let entities = crate::Post::list(&db, 1, 10, None, Vec::new(), "", "comment_count", &SortOrder::Desc).await.unwrap().0;
+        let first = entities.first().unwrap();
+        assert_eq!(first.primary_key.as_deref(), Some("42"));
+        assert_eq!(first.values.get("comment_count").unwrap(), "3");
+    }
+
+    #[actix_web::test]
+    async fn show_contains_computed_columns() {
+        let db = super::setup_db(true).await;
+        let app = create_app!(db);
+
+        let req = test::TestRequest::get()
+            .uri("/admin/post/show/5")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_success());
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        let body = body.as_str();
+        assert!(body.contains("Comments"));
+        assert!(body.contains("Title Length"));
+    }
+}
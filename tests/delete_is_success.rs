@@ -72,6 +72,30 @@ mod post_delete_is_success {
         assert!(entity_after_delete.is_none());
     }
 
+    #[actix_web::test]
+    async fn comment_delete_aborted_by_hook() {
+        let db = super::setup_db(true).await;
+        let app = create_app!(db);
+        let id = 1;
+        super::test_setup::Comment::update_many()
+            .col_expr(super::test_setup::comment::Column::Comment, Expr::value("protected"))
+            .filter(super::test_setup::comment::Column::Id.eq(id))
+            .exec(&db)
+            .await
+            .unwrap();
+
+        let uri = format!("/admin/comment/delete/{}", id);
+        let req = test::TestRequest::delete().uri(&uri).to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::UNPROCESSABLE_ENTITY);
+
+        let entity_after_delete = super::test_setup::Comment::find_by_id(id)
+            .one(&db)
+            .await
+            .unwrap();
+        assert!(entity_after_delete.is_some());
+    }
+
     #[actix_web::test]
     async fn comment_delete_many() {
         let db = super::setup_db(true).await;
@@ -104,6 +128,64 @@ mod post_delete_is_success {
         }
     }
 
+    #[actix_web::test]
+    async fn comment_delete_many_reports_failures() {
+        let db = super::setup_db(true).await;
+        let app = create_app!(db);
+
+        // a missing id rolls back the whole delete
+        let req = test::TestRequest::delete()
+            .uri("/admin/comment/delete")
+            .insert_header(ContentType::form_url_encoded())
+            .insert_header((actix_web::http::header::ACCEPT, "application/json"))
+            .set_payload("ids=1&ids=2&ids=5000")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::NOT_FOUND);
+        let body = actix_web::body::to_bytes(resp.into_body()).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json, serde_json::json!([{ "id": 5000, "error": "Entity does not exist" }]));
+
+        // a malformed id is reported instead of panicking and prevents the delete
+        let req = test::TestRequest::delete()
+            .uri("/admin/comment/delete")
+            .insert_header(ContentType::form_url_encoded())
+            .insert_header((actix_web::http::header::ACCEPT, "application/json"))
+            .set_payload("ids=1&ids=abc")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::BAD_REQUEST);
+        let body = actix_web::body::to_bytes(resp.into_body()).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json, serde_json::json!([{ "id": null, "error": "Invalid id abc" }]));
+
+        super::test_setup::Comment::update_many()
+            .col_expr(super::test_setup::comment::Column::Comment, Expr::value("protected"))
+            .filter(super::test_setup::comment::Column::Id.eq(2))
+            .exec(&db)
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::delete()
+            .uri("/admin/comment/delete")
+            .insert_header(ContentType::form_url_encoded())
+            .set_payload("ids=1&ids=2")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(resp.headers().get("HX-Retarget").unwrap(), "#notifications");
+        let body = actix_web::body::to_bytes(resp.into_body()).await.unwrap();
+        assert!(std::str::from_utf8(&body).unwrap().contains("2: Comment is protected"));
+
+        for id in [1, 2] {
+            let entity = super::test_setup::Comment::find_by_id(id)
+                .one(&db)
+                .await
+                .unwrap();
+            assert!(entity.is_some());
+        }
+    }
+
     #[actix_web::test]
     async fn post_delete_many() {
         let db = super::setup_db(true).await;
@@ -128,6 +210,11 @@ mod post_delete_is_success {
 
         // Fails because of FK constraints
         assert!(resp.status().is_server_error());
+        let entity = super::test_setup::Post::find_by_id(1)
+            .one(&db)
+            .await
+            .unwrap();
+        assert!(entity.is_some());
 
         // Remove FK
         let update_res = super::test_setup::Comment::update_many()
@@ -0,0 +1,77 @@
+mod test_setup;
+use test_setup::prelude::*;
+
+#[cfg(test)]
+mod error_is_success {
+    use actix_admin::prelude::*;
+    use actix_web::body::to_bytes;
+    use actix_web::http::header::ContentType;
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use actix_web::App;
+    use actix_web::ResponseError;
+    use sea_orm::{ConnectionTrait, DbErr};
+    use super::create_app;
+    use super::BodyTest;
+
+    #[actix_web::test]
+    async fn missing_entity_is_not_found() {
+        let db = super::setup_db(false).await;
+        let app = create_app!(db);
+
+        for uri in ["/admin/post/show/1", "/admin/post/edit/1"] {
+            let req = test::TestRequest::get().uri(uri).to_request();
+            let resp = test::call_service(&app, req).await;
+            assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+            let body = to_bytes(resp.into_body()).await.unwrap();
+            assert!(body.as_str().contains("Entity does not exist"));
+        }
+
+        let req = test::TestRequest::delete().uri("/admin/post/delete/1").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn validation_errors_are_unprocessable() {
+        let db = super::setup_db(false).await;
+        let app = create_app!(db);
+
+        let req = test::TestRequest::post()
+            .insert_header(ContentType::form_url_encoded())
+            .uri("/admin/comment/create_post_from_plaintext")
+            .set_payload("insert_date=1977-04-01T14:00&comment=test&user=test&is_visible=true&my_decimal=1")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        assert!(body.as_str().contains("Form has validation errors"));
+    }
+
+    #[actix_web::test]
+    async fn database_error_keeps_source() {
+        let err = ActixAdminError::from(DbErr::Custom("connection lost".to_string()));
+
+        assert_eq!(err.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(err.get_error_chain(), vec!["Database error", "Custom Error: connection lost"]);
+    }
+
+    #[actix_web::test]
+    async fn error_details_are_only_shown_in_debug_mode() {
+        for debug_mode in [false, true] {
+            let db = super::setup_db(true).await;
+            db.execute_unprepared("DROP TABLE comment").await.unwrap();
+            let mut actix_admin_builder = super::create_actix_admin_builder();
+            actix_admin_builder.actix_admin.configuration.debug_mode = debug_mode;
+            let app = create_app!(db, actix_admin_builder);
+
+            let req = test::TestRequest::get().uri("/admin/comment/show/1").to_request();
+            let resp = test::call_service(&app, req).await;
+            assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+            let body = to_bytes(resp.into_body()).await.unwrap();
+            let body = body.as_str();
+            assert!(body.contains("Database error"));
+            assert_eq!(body.contains("no such table"), debug_mode);
+        }
+    }
+}
@@ -12,6 +12,10 @@ mod get_request_is_success {
     use sea_orm::EntityTrait;
     use sea_orm::PaginatorTrait;
     use sea_orm::QueryOrder;
+    use sea_orm::QueryFilter;
+    use sea_orm::ColumnTrait;
+    use sea_orm::sea_query::Expr;
+    use chrono::NaiveDate;
     use super::create_app;
     use super::BodyTest;
 
@@ -139,6 +143,171 @@ mod get_request_is_success {
         test_get_is_success(url.as_str(), &db).await
     }
 
+    #[actix_web::test]
+    async fn get_post_create_with_field_attributes() {
+        let db = super::setup_db(false).await;
+        let url = format!("/admin/{}/create", crate::Post::get_entity_name());
+
+        test_response_contains(url.as_str(), &db, vec!(
+            "Headline *".to_string(),
+            "<p class=\"help\">Shown above the post</p>".to_string(),
+            "placeholder=\"A catchy headline\"".to_string(),
+            "placeholder=\"text\"".to_string()
+        )).await
+    }
+
+    #[actix_web::test]
+    async fn get_post_edit_with_field_modes() {
+        let db = super::setup_db(true).await;
+        let url = format!("/admin/{}/edit/1", crate::Post::get_entity_name());
+
+        let app = create_app!(db);
+        let req = test::TestRequest::get()
+            .uri(url.as_str())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_success());
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        let body = body.as_str();
+
+        assert!(body.contains("name=\"title\""));
+        assert!(body.contains("disabled"), "read-only field is not disabled");
+        assert!(!body.contains("name=\"view_count\""));
+        assert!(!body.contains("name=\"reviewed_by\""));
+    }
+
+    #[actix_web::test]
+    async fn get_list_and_show_with_renderers() {
+        let db = super::setup_db(true).await;
+
+        test_response_contains("/admin/comment/list", &db, vec!(
+            "<a href=\"mailto:me@home.com\" data-id=\"1\">me@home.com</a>".to_string()
+        )).await;
+        test_response_contains("/admin/comment/show/2", &db, vec!(
+            "<a href=\"mailto:me@home.com\" data-id=\"2\">me@home.com</a>".to_string()
+        )).await;
+        test_response_contains("/admin/post/list", &db, vec!(
+            "<span class=\"tag is-info\">EverydayTea</span>".to_string()
+        )).await;
+    }
+
+    #[actix_web::test]
+    async fn get_post_list_with_field_label() {
+        let db = super::setup_db(true).await;
+        let url = format!("/admin/{}/list", crate::Post::get_entity_name());
+
+        test_response_contains(url.as_str(), &db, vec!("Headline".to_string(), "Insert Date".to_string())).await
+    }
+
+    #[actix_web::test]
+    async fn get_post_list_with_enum_filter() {
+        let db = super::setup_db(true).await;
+        crate::Post::update_many()
+            .col_expr(crate::post::Column::TeaMandatory, Expr::value("BreakfastTea"))
+            .filter(crate::post::Column::Id.lte(3))
+            .exec(&db)
+            .await
+            .unwrap();
+
+        test_response_contains("/admin/post/list", &db, vec!(
+            "name=\"filter_tea_mandatory\"".to_string(),
+            "<option  value=\"EverydayTea\">Everyday Tea</option>".to_string(),
+            "<option  value=\"BreakfastTea\">BreakfastTea</option>".to_string()
+        )).await;
+
+        let filter = |value: &str| vec!(ActixAdminViewModelFilter {
+            name: "tea_mandatory".to_string(),
+            value: Some(value.to_string()),
+            value_to: None,
+            values: None,
+            filter_type: None
+        });
+        assert_eq!(crate::Post::count(&db, filter("BreakfastTea"), "").await.unwrap(), 3);
+        assert_eq!(crate::Post::count(&db, filter("EverydayTea"), "").await.unwrap(), 996);
+        assert_eq!(crate::Post::count(&db, filter("GreenTea"), "").await.unwrap(), 999);
+    }
+
+    #[actix_web::test]
+    async fn get_comment_list_with_declared_filters() {
+        let db = super::setup_db(true).await;
+        crate::Comment::update_many()
+            .col_expr(crate::comment::Column::User, Expr::value("someone@else.com"))
+            .col_expr(crate::comment::Column::InsertDate, Expr::value(NaiveDate::from_ymd_opt(2000, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap()))
+            .filter(crate::comment::Column::Id.lte(3))
+            .exec(&db)
+            .await
+            .unwrap();
+
+        test_response_contains("/admin/comment/list", &db, vec!(
+            "name=\"filter_user\"".to_string(),
+            "type=\"datetime-local\" placeholder=\"From\" name=\"filter_insert_date_from\"".to_string(),
+            "type=\"datetime-local\" placeholder=\"To\" name=\"filter_insert_date_to\"".to_string(),
+            "<select name=\"filter_is_visible\"".to_string(),
+            "<select name=\"filter_post_id\"".to_string()
+        )).await;
+
+        let filter = |name: &str, value: &str| vec!(ActixAdminViewModelFilter {
+            name: name.to_string(),
+            value: Some(value.to_string()),
+            value_to: None,
+            values: None,
+            filter_type: None
+        });
+        assert_eq!(crate::Comment::count(&db, filter("user", "else"), "").await.unwrap(), 3);
+        assert_eq!(crate::Comment::count(&db, filter("is_visible", "1"), "").await.unwrap(), 499);
+        assert_eq!(crate::Comment::count(&db, filter("post_id", "5"), "").await.unwrap(), 1);
+        assert_eq!(crate::Comment::count(&db, filter("insert_date_to", "2001-01-01T00:00"), "").await.unwrap(), 3);
+        assert_eq!(crate::Comment::count(&db, filter("insert_date_from", "2001-01-01T00:00"), "").await.unwrap(), 996);
+        // invalid values are ignored
+        assert_eq!(crate::Comment::count(&db, filter("insert_date_from", "tomorrow"), "").await.unwrap(), 999);
+    }
+
+    #[actix_web::test]
+    async fn get_post_list_with_range_filter() {
+        let db = super::setup_db(true).await;
+
+        test_response_contains("/admin/post/list?filter_id_from=10&filter_id_to=20", &db, vec!(
+            "value=\"10\" type=\"number\" step=\"1\" placeholder=\"From\" name=\"filter_id_from\"".to_string(),
+            "value=\"20\" type=\"number\" step=\"1\" placeholder=\"To\" name=\"filter_id_to\"".to_string()
+        )).await;
+
+        let filter = |from: Option<&str>, to: Option<&str>| vec!(
+            ActixAdminViewModelFilter {
+                name: "id_from".to_string(),
+                value: from.map(|from| from.to_string()),
+                value_to: None,
+                values: None,
+                filter_type: None
+            },
+            ActixAdminViewModelFilter {
+                name: "id_to".to_string(),
+                value: to.map(|to| to.to_string()),
+                value_to: None,
+                values: None,
+                filter_type: None
+            }
+        );
+        assert_eq!(crate::Post::count(&db, filter(Some("10"), Some("20")), "").await.unwrap(), 11);
+        assert_eq!(crate::Post::count(&db, filter(Some("990"), None), "").await.unwrap(), 10);
+        assert_eq!(crate::Post::count(&db, filter(None, Some("5")), "").await.unwrap(), 5);
+        assert_eq!(crate::Post::count(&db, filter(None, None), "").await.unwrap(), 999);
+    }
+
+    #[actix_web::test]
+    async fn get_comment_list_ignores_bounds_of_filters_without_range() {
+        let db = super::setup_db(true).await;
+        let app = create_app!(db);
+
+        let req = test::TestRequest::get().uri("/admin/comment/list?filter_user_from=else").to_request();
+        let resp = test::call_service(&app, req).await;
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        assert!(!body.as_str().contains("value=\"else\" type=\"text\""));
+
+        test_response_contains("/admin/comment/list?filter_user=else", &db, vec!(
+            "value=\"else\" type=\"text\" placeholder=\"\" name=\"filter_user\"".to_string()
+        )).await;
+    }
+
     async fn test_response_contains(url: &str, db: &DatabaseConnection, elements_to_verify: Vec<String>) {
         let app = create_app!(db);     
 
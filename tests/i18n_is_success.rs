@@ -0,0 +1,98 @@
+mod test_setup;
+use test_setup::prelude::*;
+
+#[cfg(test)]
+mod i18n_is_success {
+    use actix_admin::prelude::*;
+    use actix_web::body::to_bytes;
+    use actix_web::http::header::ACCEPT_LANGUAGE;
+    use actix_web::test;
+    use actix_web::App;
+    use chrono::Local;
+    use super::create_app;
+    use super::BodyTest;
+
+    #[actix_web::test]
+    async fn list_uses_default_locale() {
+        let db = super::setup_db(true).await;
+        let app = create_app!(db);
+        let today = Local::now().date_naive();
+
+        let req = test::TestRequest::get()
+            .uri("/admin/post/list")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_success());
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        let body = body.as_str();
+        assert!(body.contains("Entities per Page"));
+        assert!(body.contains("Insert Date"));
+        assert!(body.contains(&today.format("%Y-%m-%d").to_string()));
+    }
+
+    #[actix_web::test]
+    async fn locale_redirects_back_to_admin_pages_only() {
+        let db = super::setup_db(false).await;
+        let app = create_app!(db);
+
+        let set_locale = |referer: &str| test::TestRequest::post()
+            .uri("/admin/locale/de")
+            .insert_header((actix_web::http::header::REFERER, referer.to_string()))
+            .to_request();
+        let location = |resp: &actix_web::dev::ServiceResponse| resp.headers().get(actix_web::http::header::LOCATION).unwrap().to_str().unwrap().to_string();
+
+        let resp = test::call_service(&app, set_locale("http://localhost:8080/admin/post/list?page=2")).await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::SEE_OTHER);
+        assert_eq!(location(&resp), "/admin/post/list?page=2");
+
+        let resp = test::call_service(&app, set_locale("/admin/comment/list")).await;
+        assert_eq!(location(&resp), "/admin/comment/list");
+
+        for referer in ["https://evil.example/admin/post/list", "//evil.example/admin/", "http://localhost:8080/other", "javascript:alert(1)"] {
+            let resp = test::call_service(&app, set_locale(referer)).await;
+            assert_eq!(location(&resp), "/admin/");
+        }
+
+        // the locale is only changed by a post
+        let req = test::TestRequest::get().uri("/admin/locale/de").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert!(!resp.status().is_redirection());
+
+        let req = test::TestRequest::post().uri("/admin/locale/xx").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn list_uses_accept_language() {
+        let db = super::setup_db(true).await;
+        let app = create_app!(db);
+        let today = Local::now().date_naive();
+
+        let req = test::TestRequest::get()
+            .uri("/admin/post/list")
+            .insert_header((ACCEPT_LANGUAGE, "fr-CH, de-CH;q=0.9, en;q=0.8"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_success());
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        let body = body.as_str();
+        assert!(body.contains("Einträge pro Seite"));
+        assert!(body.contains("lang=\"de\""));
+        assert!(body.contains(&today.format("%d.%m.%Y").to_string()));
+    }
+
+    #[actix_web::test]
+    async fn locale_formats_values() {
+        let locale = ActixAdminLocale::from_json(include_str!("../src/locales/de.json")).unwrap();
+
+        assert_eq!(locale.format_value("1234567.5", &ActixAdminViewModelFieldType::Number), "1.234.567,5");
+        assert_eq!(locale.format_value("-123", &ActixAdminViewModelFieldType::Number), "-123");
+        assert_eq!(locale.format_value("2023-07-01", &ActixAdminViewModelFieldType::Date), "01.07.2023");
+        assert_eq!(locale.format_value("text", &ActixAdminViewModelFieldType::Text), "text");
+        assert_eq!(locale.get_field_label("post", "insert_date", None), "Insert Date");
+        assert_eq!(locale.get_field_label("comment", "post_id", None), "Post");
+        assert_eq!(locale.get_field_label("comment", "id", None), "Id");
+        assert_eq!(locale.translate("Save"), "Speichern");
+    }
+}
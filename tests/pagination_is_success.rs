@@ -0,0 +1,175 @@
+mod test_setup;
+use test_setup::prelude::*;
+
+#[cfg(test)]
+mod pagination_is_success {
+    use actix_admin::prelude::*;
+    use actix_admin::pagination::MAX_CACHED_COUNTS;
+    use actix_web::body::to_bytes;
+    use actix_web::{test, App};
+    use super::create_app;
+    use super::BodyTest;
+
+    async fn get_titles(db: &sea_orm::DatabaseConnection, page: u64, cursor: Option<ActixAdminCursor>, sort_order: &SortOrder) -> (Vec<String>, Vec<i32>, bool) {
+        let (entities, has_more) = crate::Post::list(db, page, 10, cursor, Vec::new(), "", "title", sort_order)
+            .await
+            .unwrap();
+        let titles = entities.iter().map(|e| e.values.get("title").unwrap().to_string()).collect();
+        let ids = entities.iter().map(|e| e.primary_key.as_ref().unwrap().parse::<i32>().unwrap()).collect();
+        (titles, ids, has_more)
+    }
+
+    #[actix_web::test]
+    async fn post_keyset_matches_offset() {
+        let db = super::setup_db(true).await;
+
+        for sort_order in [SortOrder::Asc, SortOrder::Desc] {
+            let (first_page, first_ids, has_more) = get_titles(&db, 1, None, &sort_order).await;
+            assert!(has_more);
+            let (second_page, second_ids, _) = get_titles(&db, 2, None, &sort_order).await;
+
+            let (after_page, _, has_more) = get_titles(&db, 1, Some(ActixAdminCursor::After(*first_ids.last().unwrap())), &sort_order).await;
+            assert!(has_more);
+            assert_eq!(after_page, second_page);
+
+            let (before_page, _, has_more) = get_titles(&db, 1, Some(ActixAdminCursor::Before(*second_ids.first().unwrap())), &sort_order).await;
+            assert!(!has_more);
+            assert_eq!(before_page, first_page);
+        }
+    }
+
+    #[actix_web::test]
+    async fn post_count() {
+        let db = super::setup_db(true).await;
+
+        let num_entities = crate::Post::count(&db, Vec::new(), "Test 15").await.unwrap();
+        assert_eq!(num_entities, 11);
+    }
+
+    #[actix_web::test]
+    async fn get_post_list_keyset() {
+        let db = super::setup_db(true).await;
+        let mut actix_admin_builder = super::create_actix_admin_builder();
+        let mut post_view_model = ActixAdminViewModel::from(crate::Post);
+        post_view_model.pagination_type = ActixAdminPaginationType::Keyset;
+        actix_admin_builder.add_entity::<crate::Post>(&post_view_model);
+        let app = create_app!(db, actix_admin_builder);
+
+        let req = test::TestRequest::get().uri("/admin/post/list?after=10&entities_per_page=5").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_success());
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        let body = body.as_str();
+        assert!(body.contains("Test 11"));
+        assert!(body.contains("before=11"));
+        assert!(body.contains("after=15"));
+        assert!(body.contains("999 entities"));
+    }
+
+    #[actix_web::test]
+    async fn get_post_list_keyset_keeps_filters() {
+        let db = super::setup_db(true).await;
+        let mut actix_admin_builder = super::create_actix_admin_builder();
+        let mut post_view_model = ActixAdminViewModel::from(crate::Post);
+        post_view_model.pagination_type = ActixAdminPaginationType::Keyset;
+        actix_admin_builder.add_entity::<crate::Post>(&post_view_model);
+        let app = create_app!(db, actix_admin_builder);
+
+        let req = test::TestRequest::get().uri("/admin/post/list?after=10&entities_per_page=5&filter_id_to=20").to_request();
+        let resp = test::call_service(&app, req).await;
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        let body = body.as_str();
+        assert!(body.contains("list?entities_per_page=5&amp;filter_id_to=20&after=15"));
+        assert!(body.contains("list?entities_per_page=5&amp;filter_id_to=20&before=11"));
+
+        let req = test::TestRequest::get().uri("/admin/post/list?after=15&entities_per_page=5&filter_id_to=20").to_request();
+        let resp = test::call_service(&app, req).await;
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        let body = body.as_str();
+        assert!(body.contains("Test 20"));
+        assert!(!body.contains("after=20"));
+    }
+
+    #[actix_web::test]
+    async fn get_post_list_keyset_ignores_nullable_sort_column() {
+        let db = super::setup_db(true).await;
+        let mut actix_admin_builder = super::create_actix_admin_builder();
+        let mut post_view_model = ActixAdminViewModel::from(crate::Post);
+        post_view_model.pagination_type = ActixAdminPaginationType::Keyset;
+        actix_admin_builder.add_entity::<crate::Post>(&post_view_model);
+        let app = create_app!(db, actix_admin_builder);
+
+        // published_at is NULL for all posts, the list is sorted by the primary key instead
+        let req = test::TestRequest::get().uri("/admin/post/list?after=10&entities_per_page=5&sort_by=published_at").to_request();
+        let resp = test::call_service(&app, req).await;
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        let body = body.as_str();
+        assert!(body.contains("Test 11"));
+        assert!(body.contains("after=15"));
+        assert!(!body.contains("sort_by('published_at')"));
+        assert!(body.contains("sort_by('title')"));
+    }
+
+    #[actix_web::test]
+    async fn get_post_list_offset_without_count() {
+        let db = super::setup_db(true).await;
+        let mut actix_admin_builder = super::create_actix_admin_builder();
+        let mut post_view_model = ActixAdminViewModel::from(crate::Post);
+        post_view_model.count_type = ActixAdminCountType::None;
+        actix_admin_builder.add_entity::<crate::Post>(&post_view_model);
+        let app = create_app!(db, actix_admin_builder);
+
+        let req = test::TestRequest::get().uri("/admin/post/list?page=3&entities_per_page=5").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_success());
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        let body = body.as_str();
+        assert!(body.contains("Test 11"));
+        assert!(body.contains("page=2"));
+        assert!(body.contains("page=4"));
+        assert!(!body.contains("page=200"));
+    }
+
+    #[actix_web::test]
+    async fn count_cache_expires() {
+        let cache = ActixAdminCountCache::default();
+        let key = ActixAdminCountCache::get_key("post", &[], "Test");
+
+        cache.insert(key.clone(), 42, &std::time::Duration::from_secs(60));
+        assert_eq!(cache.get(&key, &std::time::Duration::from_secs(60)), Some(42));
+        assert_eq!(cache.get(&key, &std::time::Duration::ZERO), None);
+        assert_eq!(cache.get(&key, &std::time::Duration::from_secs(60)), None);
+
+        // expired counts are removed when another count is inserted
+        cache.insert("expired".to_string(), 1, &std::time::Duration::ZERO);
+        cache.insert(key.clone(), 42, &std::time::Duration::from_secs(60));
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[actix_web::test]
+    async fn count_cache_is_bounded() {
+        let cache = ActixAdminCountCache::default();
+        let ttl = std::time::Duration::from_secs(60);
+        for i in 0..MAX_CACHED_COUNTS + 10 {
+            cache.insert(format!("search {}", i), i as u64, &ttl);
+        }
+        assert_eq!(cache.len(), MAX_CACHED_COUNTS);
+        assert_eq!(cache.get("search 0", &ttl), None);
+        assert_eq!(cache.get(&format!("search {}", MAX_CACHED_COUNTS + 9), &ttl), Some((MAX_CACHED_COUNTS + 9) as u64));
+    }
+
+    #[actix_web::test]
+    async fn count_cache_keys_are_encoded() {
+        let filter = |name: &str, value: &str| ActixAdminViewModelFilter {
+            name: name.to_string(),
+            value: Some(value.to_string()),
+            value_to: None,
+            values: None,
+            filter_type: None
+        };
+        assert_ne!(
+            ActixAdminCountCache::get_key("post", &[], "a&b=c"),
+            ActixAdminCountCache::get_key("post", &[filter("b", "c")], "a")
+        );
+    }
+}
@@ -9,7 +9,7 @@ mod post_create_and_edit_is_success {
         App, 
         http::header::ContentType
     };
-    use chrono::{ NaiveDateTime, NaiveDate };
+    use chrono::{ NaiveDateTime, NaiveDate, NaiveTime };
     use serde::{Serialize};
     use sea_orm::{ PaginatorTrait, EntityTrait, prelude::Decimal};
     
@@ -23,7 +23,8 @@ mod post_create_and_edit_is_success {
         user: &'static str,
         is_visible: &'static str,
         post_id: Option<&'static str>,
-        my_decimal: &'static str
+        my_decimal: &'static str,
+        version: &'static str
     }
 
     #[derive(Serialize, Clone)]
@@ -33,6 +34,9 @@ mod post_create_and_edit_is_success {
         text: &'static str,
         tea_mandatory: &'static str,
         insert_date: &'static str,
+        slug: &'static str,
+        view_count: &'static str,
+        reviewed_by: &'static str,
     }
 
     #[actix_web::test]
@@ -48,7 +52,8 @@ mod post_create_and_edit_is_success {
             user: "test",
             is_visible: "true",
             post_id: None,
-            my_decimal: "113.141" // must be larger than 100
+            my_decimal: "113.141", // must be larger than 100
+            version: "1"
         };
         
         let req = test::TestRequest::post()
@@ -119,7 +124,10 @@ mod post_create_and_edit_is_success {
             insert_date: "1977-04-01",
             title: "test",
             text: "test",
-            tea_mandatory: "EverydayTea"
+            tea_mandatory: "EverydayTea",
+            slug: "test",
+            view_count: "5",
+            reviewed_by: "admin"
         };
 
         let req = test::TestRequest::post()
@@ -144,12 +152,16 @@ mod post_create_and_edit_is_success {
         assert_eq!(entity.title, model.title);
         assert_eq!(entity.text, model.text);
         assert_eq!(entity.insert_date, NaiveDate::parse_from_str("1977-04-01", "%Y-%m-%d").unwrap());
+        assert_eq!(entity.slug.as_deref(), Some("test"));
+        assert!(entity.view_count.is_none());
+        assert!(entity.reviewed_by.is_none());
 
         // update entity
         model.tea_mandatory = "BreakfastTea";
         model.title = "updated";
         model.text = "updated";
         model.insert_date = "1987-04-01";
+        model.slug = "updated";
 
         let edit_req = test::TestRequest::post()
             .insert_header(ContentType::form_url_encoded())
@@ -172,5 +184,245 @@ mod post_create_and_edit_is_success {
         assert_eq!(entity.text, "updated");
         assert_eq!(entity.title, "updated");
         assert_eq!(entity.insert_date, NaiveDate::parse_from_str("1987-04-01", "%Y-%m-%d").unwrap());
+        assert_eq!(entity.slug.as_deref(), Some("test"), "read-only field was changed by edit");
+        assert!(entity.view_count.is_none());
+    }
+
+    #[actix_web::test]
+    async fn post_create_with_json() {
+        let db = super::setup_db(false).await;
+        let app = create_app!(db);
+
+        let payload = r#"title=test&text=test&tea_mandatory=EverydayTea&insert_date=1977-04-01&metadata={"tags": ["a \"quoted\" tag"], "rating": 5}"#;
+        let req = test::TestRequest::post()
+            .insert_header(ContentType::form_url_encoded())
+            .uri("/admin/post/create_post_from_plaintext")
+            .set_payload(payload)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_redirection());
+
+        let entity = super::test_setup::Post::find_by_id(1).one(&db).await.unwrap().unwrap();
+        assert_eq!(entity.metadata, Some(serde_json::json!({ "tags": ["a \"quoted\" tag"], "rating": 5 })));
+
+        let req = test::TestRequest::get().uri("/admin/post/show/1").to_request();
+        let resp = test::call_service(&app, req).await;
+        let body = actix_web::body::to_bytes(resp.into_body()).await.unwrap();
+        let body = std::str::from_utf8(&body).unwrap();
+        assert!(body.contains("<pre>{\n  &quot;rating&quot;: 5,"));
+
+        let payload = r#"title=test&text=test&tea_mandatory=EverydayTea&insert_date=1977-04-01&metadata={"tags": "#;
+        let req = test::TestRequest::post()
+            .insert_header(ContentType::form_url_encoded())
+            .uri("/admin/post/edit_post_from_plaintext/1")
+            .set_payload(payload)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::UNPROCESSABLE_ENTITY);
+        let body = actix_web::body::to_bytes(resp.into_body()).await.unwrap();
+        assert!(std::str::from_utf8(&body).unwrap().contains("Invalid JSON: EOF while parsing"));
+
+        let entity = super::test_setup::Post::find_by_id(1).one(&db).await.unwrap().unwrap();
+        assert!(entity.metadata.is_some(), "invalid json was stored");
+    }
+
+    #[actix_web::test]
+    async fn post_create_with_number_and_time() {
+        let db = super::setup_db(false).await;
+        let app = create_app!(db);
+
+        let req = test::TestRequest::get().uri("/admin/post/create").to_request();
+        let resp = test::call_service(&app, req).await;
+        let body = actix_web::body::to_bytes(resp.into_body()).await.unwrap();
+        let body = std::str::from_utf8(&body).unwrap();
+        assert!(body.contains("type=\"number\""));
+        assert!(body.contains("step=\"any\""));
+        assert!(body.contains("type=\"time\""));
+
+        let number_input = ActixAdminNumberInput::from_type_path("i16").unwrap();
+        assert_eq!((number_input.step.as_str(), number_input.min.as_deref(), number_input.max.as_deref()), ("1", Some("-32768"), Some("32767")));
+
+        let payload = "title=test&text=test&tea_mandatory=EverydayTea&insert_date=1977-04-01&rating=4.5&publish_time=08:30";
+        let req = test::TestRequest::post()
+            .insert_header(ContentType::form_url_encoded())
+            .uri("/admin/post/create_post_from_plaintext")
+            .set_payload(payload)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_redirection());
+
+        let entity = super::test_setup::Post::find_by_id(1).one(&db).await.unwrap().unwrap();
+        assert_eq!(entity.rating, Some(4.5));
+        assert_eq!(entity.publish_time, NaiveTime::from_hms_opt(8, 30, 0));
+
+        let payload = "title=test&text=test&tea_mandatory=EverydayTea&insert_date=1977-04-01&rating=high&publish_time=25:00";
+        let req = test::TestRequest::post()
+            .insert_header(ContentType::form_url_encoded())
+            .uri("/admin/post/edit_post_from_plaintext/1")
+            .set_payload(payload)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::UNPROCESSABLE_ENTITY);
+
+        let entity = super::test_setup::Post::find_by_id(1).one(&db).await.unwrap().unwrap();
+        assert_eq!(entity.rating, Some(4.5), "invalid number was stored");
+    }
+
+    #[actix_web::test]
+    async fn comment_create_and_edit_with_async_validation() {
+        let db = super::setup_db(true).await;
+        let app = create_app!(db);
+
+        let payload = "comment=Test 5&user=test&insert_date=1977-04-01T14:00&is_visible=true&my_decimal=113.141";
+        let req = test::TestRequest::post()
+            .insert_header(ContentType::form_url_encoded())
+            .uri("/admin/comment/create_post_from_plaintext")
+            .set_payload(payload)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::UNPROCESSABLE_ENTITY);
+        let body = actix_web::body::to_bytes(resp.into_body()).await.unwrap();
+        assert!(std::str::from_utf8(&body).unwrap().contains("Already exists"));
+
+        let payload = "comment=new&user=test&insert_date=1977-04-01T14:00&is_visible=true&post_id=5000&my_decimal=113.141";
+        let req = test::TestRequest::post()
+            .insert_header(ContentType::form_url_encoded())
+            .uri("/admin/comment/create_post_from_plaintext")
+            .set_payload(payload)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::UNPROCESSABLE_ENTITY);
+        let body = actix_web::body::to_bytes(resp.into_body()).await.unwrap();
+        assert!(std::str::from_utf8(&body).unwrap().contains("Post 5000 does not exist"));
+        assert_eq!(super::test_setup::Comment::find().count(&db).await.unwrap(), 999);
+
+        // the edited entity itself is no duplicate
+        let payload = "comment=Test 5&user=updated&insert_date=1977-04-01T14:00&is_visible=true&post_id=5&my_decimal=113.141&version=1";
+        let req = test::TestRequest::post()
+            .insert_header(ContentType::form_url_encoded())
+            .uri("/admin/comment/edit_post_from_plaintext/5")
+            .set_payload(payload)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_redirection());
+
+        let entity = super::test_setup::Comment::find_by_id(5).one(&db).await.unwrap().unwrap();
+        assert_eq!(entity.user, "updated");
+    }
+
+    #[actix_web::test]
+    async fn post_create_with_form_errors() {
+        let db = super::setup_db(false).await;
+        let app = create_app!(db);
+
+        let payload = "title=test&text=test&tea_mandatory=EverydayTea&insert_date=2024-01-01&published_at=2023-07-01T10:00";
+        let req = test::TestRequest::post()
+            .insert_header(ContentType::form_url_encoded())
+            .uri("/admin/post/create_post_from_plaintext")
+            .set_payload(payload)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::UNPROCESSABLE_ENTITY);
+        let body = actix_web::body::to_bytes(resp.into_body()).await.unwrap();
+        let body = std::str::from_utf8(&body).unwrap();
+        assert!(body.contains("form-errors"));
+        assert!(body.contains("<li>Must be published after the insert date</li>"));
+
+        let req = test::TestRequest::post()
+            .insert_header(ContentType::form_url_encoded())
+            .insert_header((actix_web::http::header::ACCEPT, "application/json"))
+            .uri("/admin/post/create_post_from_plaintext")
+            .set_payload(payload)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::UNPROCESSABLE_ENTITY);
+        let body = actix_web::body::to_bytes(resp.into_body()).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["form_errors"], serde_json::json!(["Must be published after the insert date"]));
+        assert_eq!(json["values"]["title"], "test");
+
+        assert_eq!(super::test_setup::Post::find().count(&db).await.unwrap(), 0);
+    }
+
+    #[actix_web::test]
+    async fn comment_create_with_hooks() {
+        let db = super::setup_db(false).await;
+        let app = create_app!(db);
+
+        let payload = "comment=hooked&user=Test@Example.com&insert_date=1977-04-01T14:00&is_visible=true&my_decimal=113.141";
+        let req = test::TestRequest::post()
+            .insert_header(ContentType::form_url_encoded())
+            .uri("/admin/comment/create_post_from_plaintext")
+            .set_payload(payload)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_redirection());
+
+        let entity = super::test_setup::Comment::find_by_id(1).one(&db).await.unwrap().unwrap();
+        assert_eq!(entity.user, "test@example.com");
+
+        // an error of the after hook rolls back the insert
+        let payload = "comment=rollback&user=test&insert_date=1977-04-01T14:00&is_visible=true&my_decimal=113.141";
+        let req = test::TestRequest::post()
+            .insert_header(ContentType::form_url_encoded())
+            .uri("/admin/comment/create_post_from_plaintext")
+            .set_payload(payload)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::UNPROCESSABLE_ENTITY);
+        let body = actix_web::body::to_bytes(resp.into_body()).await.unwrap();
+        assert!(std::str::from_utf8(&body).unwrap().contains("Comment was rolled back"));
+
+        assert_eq!(super::test_setup::Comment::find().count(&db).await.unwrap(), 1);
+    }
+
+    #[actix_web::test]
+    async fn comment_edit_with_conflict() {
+        let db = super::setup_db(true).await;
+        let app = create_app!(db);
+
+        let payload = "comment=Test 5&user=first&insert_date=1977-04-01T14:00&is_visible=true&post_id=5&my_decimal=113.141&version=1";
+        let req = test::TestRequest::post()
+            .insert_header(ContentType::form_url_encoded())
+            .uri("/admin/comment/edit_post_from_plaintext/5")
+            .set_payload(payload)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_redirection());
+
+        let entity = super::test_setup::Comment::find_by_id(5).one(&db).await.unwrap().unwrap();
+        assert_eq!(entity.version, 2);
+
+        // the second edit was loaded with the first version
+        let payload = "comment=Test 5&user=second&insert_date=1977-04-01T14:00&is_visible=true&post_id=5&my_decimal=113.141&version=1";
+        let req = test::TestRequest::post()
+            .insert_header(ContentType::form_url_encoded())
+            .uri("/admin/comment/edit_post_from_plaintext/5")
+            .set_payload(payload)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::CONFLICT);
+        let body = actix_web::body::to_bytes(resp.into_body()).await.unwrap();
+        let body = std::str::from_utf8(&body).unwrap();
+        assert!(body.contains("<td>second</td>"));
+        assert!(body.contains("<td>first</td>"));
+        assert!(body.contains("name=\"version\" value=\"2\""));
+
+        let entity = super::test_setup::Comment::find_by_id(5).one(&db).await.unwrap().unwrap();
+        assert_eq!(entity.user, "first");
+
+        // submitting the form again with the current version overwrites the first edit
+        let payload = "comment=Test 5&user=second&insert_date=1977-04-01T14:00&is_visible=true&post_id=5&my_decimal=113.141&version=2";
+        let req = test::TestRequest::post()
+            .insert_header(ContentType::form_url_encoded())
+            .uri("/admin/comment/edit_post_from_plaintext/5")
+            .set_payload(payload)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_redirection());
+
+        let entity = super::test_setup::Comment::find_by_id(5).one(&db).await.unwrap().unwrap();
+        assert_eq!(entity.user, "second");
+        assert_eq!(entity.version, 3);
     }
 }
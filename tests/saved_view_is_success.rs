@@ -0,0 +1,51 @@
+mod test_setup;
+use test_setup::prelude::*;
+
+#[cfg(test)]
+mod saved_view_is_success {
+    use actix_admin::prelude::*;
+    use actix_web::body::to_bytes;
+    use actix_web::{http::header::ContentType, test, App};
+
+    use crate::create_app;
+    use super::BodyTest;
+
+    #[actix_web::test]
+    async fn comment_save_and_delete_view() {
+        let db = super::setup_db(true).await;
+        let app = create_app!(db);
+
+        let req = test::TestRequest::post()
+            .uri("/admin/comment/saved_view")
+            .insert_header(ContentType::form_url_encoded())
+            .set_payload("saved_view_name=Visible&saved_view_is_shared=true&search=Test 1&sort_by=id&sort_order=Desc&entities_per_page=20&page=3&filter_Is Visible=1&filter_User=")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_redirection());
+        let location = resp.headers().get("location").unwrap().to_str().unwrap();
+        assert_eq!(location, "/admin/comment/list?search=Test%201&sort_by=id&sort_order=Desc&entities_per_page=20&filter_Is%20Visible=1");
+
+        let saved_views = SeaOrmSavedViewStore::default()
+            .get_saved_views(&db, "comment", None)
+            .await
+            .unwrap();
+        assert_eq!(saved_views.len(), 1);
+        assert!(saved_views[0].is_shared);
+
+        let req = test::TestRequest::get().uri("/admin/comment/list").to_request();
+        let resp = test::call_service(&app, req).await;
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        assert!(body.as_str().contains("Visible"));
+
+        let uri = format!("/admin/comment/saved_view/{}", saved_views[0].id.unwrap());
+        let req = test::TestRequest::delete().uri(&uri).to_request();
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_success());
+
+        let saved_views = SeaOrmSavedViewStore::default()
+            .get_saved_views(&db, "comment", None)
+            .await
+            .unwrap();
+        assert!(saved_views.is_empty());
+    }
+}
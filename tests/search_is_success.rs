@@ -0,0 +1,136 @@
+mod test_setup;
+use test_setup::prelude::*;
+
+#[cfg(test)]
+mod search_is_success {
+    use actix_admin::prelude::*;
+    use actix_web::body::to_bytes;
+    use actix_web::test;
+    use actix_web::App;
+    use chrono::{Local, Utc};
+    use super::create_app;
+    use super::BodyTest;
+
+    #[actix_web::test]
+    async fn parse_search_terms_with_fields() {
+        let terms: Vec<ActixAdminSearchTerm> = parse_search_terms("user:alice insert_date>2023-01-01 -spam \"exact phrase\" title:\"Test 1\"")
+            .into_iter()
+            .map(|term| term.unwrap())
+            .collect();
+
+        assert_eq!(terms.len(), 5);
+        assert_eq!(terms[0].field_name.as_deref(), Some("user"));
+        assert_eq!(terms[0].value, "alice");
+        assert_eq!(terms[1].operator, ActixAdminSearchOperator::Greater);
+        assert_eq!(terms[1].value, "2023-01-01");
+        assert!(terms[2].is_negated);
+        assert_eq!(terms[2].field_name, None);
+        assert_eq!(terms[3].value, "exact phrase");
+        assert_eq!(terms[4].value, "Test 1");
+
+        let terms = parse_search_terms("Test 15 id>3");
+        assert_eq!(terms.len(), 2);
+        assert_eq!(terms[0].as_ref().unwrap().value, "Test 15");
+    }
+
+    #[actix_web::test]
+    async fn search_post_with_fields() {
+        let db = super::setup_db(true).await;
+        let today = Local::now().date_naive().format("%Y-%m-%d");
+
+        assert_eq!(crate::Post::count(&db, Vec::new(), "title:\"Test 15\"").await.unwrap(), 11);
+        assert_eq!(crate::Post::count(&db, Vec::new(), "title=\"Test 15\"").await.unwrap(), 1);
+        assert_eq!(crate::Post::count(&db, Vec::new(), "id>=990").await.unwrap(), 10);
+        assert_eq!(crate::Post::count(&db, Vec::new(), "\"Test 15\" -\"Test 150\"").await.unwrap(), 10);
+        assert_eq!(crate::Post::count(&db, Vec::new(), &format!("insert_date={}", today)).await.unwrap(), 999);
+        assert_eq!(crate::Post::count(&db, Vec::new(), &format!("insert_date>{}", today)).await.unwrap(), 0);
+    }
+
+    #[actix_web::test]
+    async fn search_comment_with_fields() {
+        let db = super::setup_db(true).await;
+        let today = Utc::now().date_naive().format("%Y-%m-%d");
+
+        assert_eq!(crate::Comment::count(&db, Vec::new(), "is_visible:yes id<=10").await.unwrap(), 5);
+        assert_eq!(crate::Comment::count(&db, Vec::new(), &format!("insert_date:{} user:home", today)).await.unwrap(), 999);
+    }
+
+    #[actix_web::test]
+    async fn search_with_invalid_terms() {
+        let db = super::setup_db(true).await;
+
+        let invalid_terms = crate::Comment::validate_search("unknown:1 id>abc is_visible>true user: Test my_decimal:1");
+        assert_eq!(invalid_terms.len(), 4);
+        assert!(invalid_terms.contains(&"Invalid search term my_decimal:1: the field cannot be searched by value".to_string()));
+
+        // terms with a field which is not searchable are searched as text
+        assert_eq!(crate::Comment::count(&db, Vec::new(), "post_id:1").await.unwrap(), 0);
+        assert_eq!(crate::Comment::count(&db, Vec::new(), "-https://example.com").await.unwrap(), 999);
+
+        // invalid terms are ignored, the valid ones still apply
+        assert_eq!(crate::Comment::count(&db, Vec::new(), "id>abc \"Test 15\"").await.unwrap(), 11);
+
+        let app = create_app!(db);
+        let req = test::TestRequest::get()
+            .uri("/admin/comment/list?search=id%3Eabc")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_success());
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        assert!(body.as_str().contains("Invalid search term id&gt;abc: abc is not a valid number"));
+    }
+
+    #[actix_web::test]
+    async fn global_search_groups_entities() {
+        let db = super::setup_db(true).await;
+        let app = create_app!(db);
+
+        let req = test::TestRequest::get()
+            .uri("/admin/search?search=Test%20155")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_success());
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        let body = body.as_str();
+        assert!(body.contains("/admin/post/show/155"));
+        assert!(body.contains("/admin/comment/show/155"));
+        assert!(body.contains("/admin/post/list?search=Test%20155"));
+        assert!(body.contains("/admin/comment/list?search=Test%20155"));
+
+        // comments have no title, the term is searched as text and matches no comment
+        let req = test::TestRequest::get()
+            .uri("/admin/search?search=title%3A%22Test%20155%22")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_success());
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        let body = body.as_str();
+        assert!(body.contains("/admin/post/show/155"));
+        assert!(!body.contains("/admin/comment/show/155"));
+    }
+
+    #[actix_web::test]
+    async fn global_search_masks_and_renders_fields() {
+        let db = super::setup_db(true).await;
+        let mut actix_admin_builder = super::create_actix_admin_builder();
+        let mut post_view_model = ActixAdminViewModel::from(crate::Post);
+        let mask = regex::Regex::new("content").unwrap();
+        let mut fields = post_view_model.fields.to_vec();
+        for field in fields.iter_mut().filter(|field| field.field_name == "text") {
+            field.list_regex_mask = Some(mask.clone());
+        }
+        post_view_model.fields = Box::leak(fields.into_boxed_slice());
+        actix_admin_builder.add_entity::<crate::Post>(&post_view_model);
+        let app = create_app!(db, actix_admin_builder);
+
+        let req = test::TestRequest::get()
+            .uri("/admin/search?search=Test%20155")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        let body = body.as_str();
+        assert!(body.contains("some ****"));
+        assert!(!body.contains("some content"));
+        assert!(body.contains("<span class=\"tag is-info\">EverydayTea</span>"));
+    }
+}
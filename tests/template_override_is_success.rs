@@ -0,0 +1,73 @@
+mod test_setup;
+use test_setup::prelude::*;
+
+#[cfg(test)]
+mod template_override_is_success {
+    use actix_admin::prelude::*;
+    use actix_web::body::to_bytes;
+    use actix_web::test;
+    use actix_web::App;
+    use super::create_app;
+    use super::BodyTest;
+
+    #[actix_web::test]
+    async fn user_templates_override_builtin_templates() {
+        let db = super::setup_db(true).await;
+        let mut actix_admin_builder = super::create_actix_admin_builder();
+        actix_admin_builder
+            .add_template(
+                "navbar.html",
+                "<nav id=\"custom-navbar\">Custom Navbar</nav>{% include \"actix_admin/navbar.html\" %}",
+            )
+            .unwrap();
+        actix_admin_builder
+            .add_template_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/templates"))
+            .unwrap();
+        let app = create_app!(db, actix_admin_builder);
+
+        // user override of a template included by all pages
+        let req = test::TestRequest::get().uri("/admin/post/list").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_success());
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        let body = body.as_str();
+        assert!(body.contains("custom-navbar"));
+        assert!(body.contains("navbar-burger"));
+        assert!(!body.contains("comment-show-override"));
+
+        // entity-specific template which extends the built-in template
+        let req = test::TestRequest::get().uri("/admin/comment/show/1").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_success());
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        let body = body.as_str();
+        assert!(body.contains("comment-show-override"));
+        assert!(body.contains("custom-navbar"));
+        assert!(body.contains("Insert Date"));
+
+        let req = test::TestRequest::get().uri("/admin/post/show/1").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_success());
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        assert!(!body.as_str().contains("comment-show-override"));
+
+        // entity-specific error page
+        let req = test::TestRequest::get().uri("/admin/post/show/5000").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::NOT_FOUND);
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        assert!(body.as_str().contains("post-error-override"));
+
+        let req = test::TestRequest::get().uri("/admin/comment/show/5000").to_request();
+        let resp = test::call_service(&app, req).await;
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        assert!(!body.as_str().contains("post-error-override"));
+    }
+
+    #[actix_web::test]
+    async fn invalid_template_is_rejected() {
+        let mut actix_admin_builder = super::create_actix_admin_builder();
+        assert!(actix_admin_builder.add_template("list.html", "{% block content %}").is_err());
+        assert!(actix_admin_builder.add_template_dir("./does_not_exist").is_err());
+    }
+}
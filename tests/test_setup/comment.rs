@@ -10,19 +10,24 @@ use super::Post;
 pub struct Model {
     #[sea_orm(primary_key)]
     #[serde(skip_deserializing)]
-    #[actix_admin(primary_key)]
+    #[actix_admin(primary_key, searchable)]
     pub id: i32,
     #[actix_admin(searchable)]
     pub comment: String,
     #[sea_orm(column_type = "Text")]
-    #[actix_admin(html_input_type = "email")]
+    #[actix_admin(html_input_type = "email", renderer = "mailto", filter = "contains", searchable)]
     pub user: String,
     #[sea_orm(column_type = "DateTime")]
+    #[actix_admin(filter = "range", searchable)]
     pub insert_date: DateTime,
+    #[actix_admin(filter, searchable)]
     pub is_visible: bool,
-    #[actix_admin(select_list="Post")]
+    #[actix_admin(select_list="Post", filter)]
     pub post_id: Option<i32>,
-    pub my_decimal: Decimal
+    #[actix_admin(searchable)]
+    pub my_decimal: Decimal,
+    #[actix_admin(version)]
+    pub version: i32
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -43,6 +48,7 @@ impl Related<super::post::Entity> for Entity {
 
 impl ActiveModelBehavior for ActiveModel {}
 
+#[async_trait(?Send)]
 impl ActixAdminModelValidationTrait<ActiveModel> for Entity {
     fn validate(model: &ActiveModel) -> HashMap<String, String> {
         let mut errors = HashMap::new();
@@ -51,6 +57,51 @@ impl ActixAdminModelValidationTrait<ActiveModel> for Entity {
         }
         errors
     }
+
+    async fn validate_async(model: &ActiveModel, db: &DatabaseConnection, _session: &Session, mode: &ActixAdminValidationMode) -> Result<ActixAdminValidationErrors, ActixAdminError> {
+        let mut errors = ActixAdminValidationErrors::default();
+
+        let mut duplicates = Entity::find().filter(Column::Comment.eq(model.comment.clone().unwrap()));
+        if let ActixAdminValidationMode::Edit(id) = mode {
+            duplicates = duplicates.filter(Column::Id.ne(*id));
+        }
+        if duplicates.count(db).await? > 0 {
+            errors.field_errors.insert("comment".to_string(), "Already exists".to_string());
+        }
+
+        if let Some(post_id) = model.post_id.clone().unwrap() {
+            if Post::find_by_id(post_id).one(db).await?.is_none() {
+                errors.form_errors.push(format!("Post {} does not exist", post_id));
+            }
+        }
+
+        Ok(errors)
+    }
 }
 
-impl ActixAdminModelFilterTrait<Entity> for Entity {}
\ No newline at end of file
+impl ActixAdminModelFilterTrait<Entity> for Entity {}
+impl ActixAdminComputedColumnTrait<Entity> for Entity {}
+
+#[async_trait(?Send)]
+impl ActixAdminModelHooksTrait for Entity {
+    async fn before_create(_txn: &DatabaseTransaction, model: &mut ActixAdminModel) -> Result<(), ActixAdminError> {
+        if let Some(user) = model.values.get_mut("user") {
+            *user = user.to_lowercase();
+        }
+        Ok(())
+    }
+
+    async fn after_create(_txn: &DatabaseTransaction, model: &ActixAdminModel) -> Result<(), ActixAdminError> {
+        match model.values.get("comment").map(String::as_str) {
+            Some("rollback") => Err(ActixAdminError::HookError("Comment was rolled back".to_string())),
+            _ => Ok(()),
+        }
+    }
+
+    async fn before_delete(txn: &DatabaseTransaction, id: i32) -> Result<(), ActixAdminError> {
+        match Entity::find_by_id(id).one(txn).await? {
+            Some(comment) if comment.comment == "protected" => Err(ActixAdminError::HookError("Comment is protected".to_string())),
+            _ => Ok(()),
+        }
+    }
+}
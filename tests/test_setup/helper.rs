@@ -8,6 +8,8 @@ use actix_web::web::Bytes;
 use chrono::Local;
 use sea_orm::prelude::Decimal;
 use sea_orm::{ConnectOptions, DatabaseConnection, EntityTrait, Set};
+use std::collections::HashMap;
+use std::sync::Arc;
 
 use super::{comment, create_tables, post, Comment, Post};
 
@@ -16,6 +18,7 @@ pub async fn setup_db(create_entities: bool) -> DatabaseConnection {
 
     let db = sea_orm::Database::connect(opt).await.unwrap();
     let _ = create_tables(&db).await;
+    let _ = SeaOrmSavedViewStore::default().create_table(&db).await;
 
     if create_entities {
         for i in 1..1000 {
@@ -54,8 +57,11 @@ pub async fn setup_db(create_entities: bool) -> DatabaseConnection {
 #[macro_export]
 macro_rules! create_app (
     ($db: expr) => ({
+        $crate::create_app!($db, super::create_actix_admin_builder())
+    });
+    ($db: expr, $actix_admin_builder: expr) => ({
         let conn = $db.clone();
-        let actix_admin_builder = super::create_actix_admin_builder();
+        let actix_admin_builder = $actix_admin_builder;
         let actix_admin = actix_admin_builder.get_actix_admin();
 
         test::init_service(
@@ -68,6 +74,14 @@ macro_rules! create_app (
     });
 );
 
+fn render_mailto(value: &str, _field: &ActixAdminViewModelField, model: &ActixAdminModel) -> String {
+    format!("<a href=\"mailto:{0}\" data-id=\"{1}\">{0}</a>", value, model.primary_key.as_deref().unwrap_or_default())
+}
+
+fn render_badge(value: &tera::Value, _args: &HashMap<String, tera::Value>) -> tera::Result<tera::Value> {
+    Ok(tera::to_value(format!("<span class=\"tag is-info\">{}</span>", value.as_str().unwrap_or_default())).unwrap())
+}
+
 pub fn create_actix_admin_builder() -> ActixAdminBuilder {
     let post_view_model = ActixAdminViewModel::from(Post);
     let comment_view_model = ActixAdminViewModel::from(Comment);
@@ -75,15 +89,24 @@ pub fn create_actix_admin_builder() -> ActixAdminBuilder {
     let configuration = ActixAdminConfiguration {
         enable_auth: false,
         user_is_logged_in: None,
+        user_identifier: None,
         login_link: None,
         logout_link: None,
         file_upload_directory: "./file_uploads",
-        navbar_title: "test"
+        navbar_title: "test",
+        default_locale: "en",
+        default_timezone: Tz::UTC,
+        asset_urls: None,
+        theme: ActixAdminTheme::default(),
+        debug_mode: false
     };
 
     let mut admin_builder = ActixAdminBuilder::new(configuration);
     admin_builder.add_entity::<Post>(&post_view_model);
     admin_builder.add_entity::<Comment>(&comment_view_model);
+    admin_builder.add_saved_view_store(SeaOrmSavedViewStore::default());
+    admin_builder.add_renderer("mailto", ActixAdminRenderer::Fn(render_mailto));
+    admin_builder.add_renderer("badge", ActixAdminRenderer::Filter(Arc::new(render_badge)));
 
     admin_builder.add_custom_handler_for_entity::<Comment>(
         "Create Comment From Plaintext",
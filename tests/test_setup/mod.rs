@@ -41,6 +41,13 @@ pub async fn create_tables(db: &DbConn) -> Result<ExecResult, DbErr> {
         .col(ColumnDef::new(post::Column::TeaMandatory).string().not_null())
         .col(ColumnDef::new(post::Column::TeaOptional).string())
         .col(ColumnDef::new(post::Column::InsertDate).date())
+        .col(ColumnDef::new(post::Column::Slug).string())
+        .col(ColumnDef::new(post::Column::ViewCount).integer())
+        .col(ColumnDef::new(post::Column::ReviewedBy).string())
+        .col(ColumnDef::new(post::Column::Metadata).json())
+        .col(ColumnDef::new(post::Column::Rating).double())
+        .col(ColumnDef::new(post::Column::PublishTime).time())
+        .col(ColumnDef::new(post::Column::PublishedAt).timestamp_with_time_zone())
         .to_owned();
 
     let _result = create_table(db, &stmt).await;
@@ -61,6 +68,7 @@ pub async fn create_tables(db: &DbConn) -> Result<ExecResult, DbErr> {
         .col(ColumnDef::new(comment::Column::IsVisible).boolean().not_null())
         .col(ColumnDef::new(comment::Column::MyDecimal).decimal().not_null())
         .col(ColumnDef::new(comment::Column::PostId).integer())
+        .col(ColumnDef::new(comment::Column::Version).integer().not_null().default(1))
         .foreign_key(
             ForeignKeyCreateStatement::new()
                 .name("fk-comment-post")
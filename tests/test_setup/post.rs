@@ -1,27 +1,39 @@
 use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::{Expr, Query, SimpleExpr, SubQueryStatement};
 use serde::{Deserialize, Serialize};
 use actix_admin::prelude::*; 
 use std::fmt;
 use std::fmt::Display;
-use std::str::FromStr;
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Deserialize, Serialize, DeriveActixAdmin, DeriveActixAdminViewModel, DeriveActixAdminModel, DeriveActixAdminModelSelectList)]
 #[sea_orm(table_name = "post")]
 pub struct Model {
     #[sea_orm(primary_key)]
     #[serde(skip_deserializing)]
-    #[actix_admin(primary_key)]
+    #[actix_admin(primary_key, searchable)]
     pub id: i32,
-    #[actix_admin(searchable)]
+    #[actix_admin(searchable, label = "Headline", help_text = "Shown above the post", placeholder = "A catchy headline")]
     pub title: String,
     #[sea_orm(column_type = "Text")]
     #[actix_admin(searchable, textarea)]
     pub text: String,
-    #[actix_admin(select_list="Tea")]
+    #[actix_admin(select_list="Tea", renderer = "badge")]
     pub tea_mandatory: Tea,
     #[actix_admin(select_list="Tea")]
     pub tea_optional: Option<Tea>,
+    #[actix_admin(searchable)]
     pub insert_date: Date,
+    #[actix_admin(readonly_after_create)]
+    pub slug: Option<String>,
+    #[actix_admin(form_hidden)]
+    pub view_count: Option<i32>,
+    #[actix_admin(show_only)]
+    pub reviewed_by: Option<String>,
+    pub metadata: Option<Json>,
+    pub rating: Option<f64>,
+    pub publish_time: Option<Time>,
+    #[actix_admin(filter = "range")]
+    pub published_at: Option<DateTimeWithTimeZone>,
 }
 
 impl Display for Model {
@@ -50,23 +62,65 @@ impl ActiveModelBehavior for ActiveModel {}
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "tea")]
 pub enum Tea {
     #[sea_orm(string_value = "EverydayTea")]
+    #[actix_admin(label = "Everyday Tea")]
     EverydayTea,
     #[sea_orm(string_value = "BreakfastTea")]
     BreakfastTea,
 }
 
-impl FromStr for Tea {
-    type Err = ();
-
-    fn from_str(input: &str) -> Result<Tea, Self::Err> {
-        match input {
-            "EverydayTea"  => Ok(Tea::EverydayTea),
-            "BreakfastTea"  => Ok(Tea::BreakfastTea),
-            _      => Err(()),
+impl ActixAdminModelValidationTrait<ActiveModel> for Entity {
+    fn validate_form(model: &ActiveModel) -> Vec<String> {
+        match (model.published_at.clone().unwrap(), model.insert_date.clone().unwrap()) {
+            (Some(published_at), insert_date) if published_at.date_naive() < insert_date => {
+                vec!["Must be published after the insert date".to_string()]
+            }
+            _ => Vec::new(),
         }
     }
 }
 
-impl ActixAdminModelValidationTrait<ActiveModel> for Entity {}
+impl ActixAdminModelFilterTrait<Entity> for Entity {
+    fn get_range_filter() -> Vec<ActixAdminModelRangeFilter<Entity>> {
+        vec![
+            ActixAdminModelRangeFilter {
+                name: "id".to_string(),
+                filter_type: ActixAdminModelFilterType::IntegerRange,
+                filter: |q: sea_orm::Select<Entity>, from, to| -> sea_orm::Select<Entity> {
+                    q.apply_if(from.and_then(|from| from.parse::<i32>().ok()), |query, from| query.filter(Column::Id.gte(from)))
+                        .apply_if(to.and_then(|to| to.parse::<i32>().ok()), |query, to| query.filter(Column::Id.lte(to)))
+                }
+            }
+        ]
+    }
+}
+
+fn get_title_length<'a>(_db: &'a DatabaseConnection, model: &'a Model) -> ActixAdminComputedFuture<'a> {
+    Box::pin(async move { Ok(model.title.len().to_string()) })
+}
+
+impl ActixAdminComputedColumnTrait<Entity> for Entity {
+    fn get_computed_columns() -> Vec<ActixAdminComputedColumn<Entity>> {
+        let comment_count = Query::select()
+            .expr(Expr::col(super::comment::Column::Id).count())
+            .from(super::comment::Entity)
+            .and_where(Expr::col((super::comment::Entity, super::comment::Column::PostId)).equals((Entity, Column::Id)))
+            .to_owned();
+
+        vec![
+            ActixAdminComputedColumn {
+                name: "comment_count".to_string(),
+                label: Some("Comments".to_string()),
+                field_type: ActixAdminViewModelFieldType::Number,
+                value: ActixAdminComputedValue::Expr(SimpleExpr::SubQuery(None, Box::new(SubQueryStatement::SelectStatement(comment_count))))
+            },
+            ActixAdminComputedColumn {
+                name: "title_length".to_string(),
+                label: None,
+                field_type: ActixAdminViewModelFieldType::Number,
+                value: ActixAdminComputedValue::Fn(get_title_length)
+            }
+        ]
+    }
+}
 
-impl ActixAdminModelFilterTrait<Entity> for Entity {}
\ No newline at end of file
+impl ActixAdminModelHooksTrait for Entity {}
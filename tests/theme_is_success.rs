@@ -0,0 +1,58 @@
+mod test_setup;
+use test_setup::prelude::*;
+
+#[cfg(test)]
+mod theme_is_success {
+    use actix_admin::prelude::*;
+    use actix_web::body::to_bytes;
+    use actix_web::test;
+    use actix_web::App;
+    use super::create_app;
+    use super::BodyTest;
+
+    #[actix_web::test]
+    async fn default_theme_is_light() {
+        let db = super::setup_db(false).await;
+        let app = create_app!(db);
+
+        let req = test::TestRequest::get().uri("/admin/").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_success());
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        let body = body.as_str();
+        assert!(body.contains("data-theme=\"light\""));
+        assert!(!body.contains("environment-badge"));
+        assert!(!body.contains("--actix-admin-primary"));
+    }
+
+    #[actix_web::test]
+    async fn configured_theme_is_applied() {
+        let db = super::setup_db(false).await;
+        let mut actix_admin_builder = super::create_actix_admin_builder();
+        actix_admin_builder.actix_admin.configuration.theme = ActixAdminTheme {
+            mode: ActixAdminThemeMode::Auto,
+            primary_color: Some("#8e44ad".to_string()),
+            logo_url: Some("/logo.png".to_string()),
+            favicon_url: Some("/favicon.ico".to_string()),
+            environment: Some(ActixAdminEnvironment {
+                name: "Staging".to_string(),
+                color: "#e67e22".to_string(),
+            }),
+            ..Default::default()
+        };
+        let app = create_app!(db, actix_admin_builder);
+
+        let req = test::TestRequest::get().uri("/admin/post/list").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_success());
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        let body = body.as_str();
+        assert!(body.contains("data-theme=\"auto\""));
+        assert!(body.contains("content=\"light dark\""));
+        assert!(body.contains("--actix-admin-primary: #8e44ad;"));
+        assert!(body.contains("--actix-admin-environment: #e67e22;"));
+        assert!(body.contains("<span class=\"tag environment-badge\">Staging</span>"));
+        assert!(body.contains("navbar-logo"));
+        assert!(body.contains("rel=\"icon\""));
+    }
+}
@@ -0,0 +1,133 @@
+mod test_setup;
+use test_setup::prelude::*;
+
+#[cfg(test)]
+mod timezone_is_success {
+    use actix_admin::prelude::*;
+    use actix_admin::timezone::{from_local_datetime, to_local_datetime};
+    use actix_web::body::to_bytes;
+    use actix_web::http::header::ContentType;
+    use actix_web::test;
+    use actix_web::App;
+    use chrono::{TimeZone, Utc};
+    use sea_orm::EntityTrait;
+    use super::create_app;
+    use super::BodyTest;
+
+    #[actix_web::test]
+    async fn datetimes_are_converted_between_timezones() {
+        let zurich: Tz = "Europe/Zurich".parse().unwrap();
+
+        assert_eq!(from_local_datetime("2023-07-01T10:00:30", &zurich).as_deref(), Some("2023-07-01T10:00:30+02:00"));
+        assert_eq!(from_local_datetime("2023-01-01T10:00", &zurich).as_deref(), Some("2023-01-01T10:00:00+01:00"));
+        assert_eq!(to_local_datetime("2023-07-01 08:00:30 +00:00", &zurich).as_deref(), Some("2023-07-01 10:00:30"));
+        assert_eq!(to_local_datetime("2023-07-01T08:00:30Z", &Tz::UTC).as_deref(), Some("2023-07-01 08:00:30"));
+        assert_eq!(from_local_datetime("tomorrow", &zurich), None);
+    }
+
+    #[actix_web::test]
+    async fn post_with_datetime_in_configured_timezone() {
+        let db = super::setup_db(false).await;
+        let mut actix_admin_builder = super::create_actix_admin_builder();
+        actix_admin_builder.actix_admin.configuration.default_timezone = "Europe/Zurich".parse().unwrap();
+        let app = create_app!(db, actix_admin_builder);
+
+        let payload = "title=test&text=test&tea_mandatory=EverydayTea&insert_date=1977-04-01&published_at=2023-07-01T10:00:30";
+        let req = test::TestRequest::post()
+            .insert_header(ContentType::form_url_encoded())
+            .uri("/admin/post/create_post_from_plaintext")
+            .set_payload(payload)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_redirection());
+
+        let entity = super::test_setup::Post::find_by_id(1).one(&db).await.unwrap().unwrap();
+        let published_at = entity.published_at.unwrap();
+        assert_eq!(published_at, Utc.with_ymd_and_hms(2023, 7, 1, 8, 0, 30).unwrap());
+
+        let req = test::TestRequest::get().uri("/admin/post/show/1").to_request();
+        let resp = test::call_service(&app, req).await;
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        assert!(body.as_str().contains("2023-07-01 10:00:30"));
+
+        let req = test::TestRequest::get().uri("/admin/post/edit/1").to_request();
+        let resp = test::call_service(&app, req).await;
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        assert!(body.as_str().contains("value=\"2023-07-01 10:00:30\""));
+
+        let payload = "title=test&text=test&tea_mandatory=EverydayTea&insert_date=1977-04-01&published_at=someday";
+        let req = test::TestRequest::post()
+            .insert_header(ContentType::form_url_encoded())
+            .uri("/admin/post/edit_post_from_plaintext/1")
+            .set_payload(payload)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::UNPROCESSABLE_ENTITY);
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        assert!(body.as_str().contains("value=\"someday\""));
+    }
+
+    #[actix_web::test]
+    async fn filters_are_read_in_configured_timezone() {
+        let db = super::setup_db(false).await;
+        let mut actix_admin_builder = super::create_actix_admin_builder();
+        actix_admin_builder.actix_admin.configuration.default_timezone = "Europe/Zurich".parse().unwrap();
+        let app = create_app!(db, actix_admin_builder);
+
+        let payload = "title=test&text=test&tea_mandatory=EverydayTea&insert_date=1977-04-01&published_at=2023-07-01T10:00:30";
+        let req = test::TestRequest::post()
+            .insert_header(ContentType::form_url_encoded())
+            .uri("/admin/post/create_post_from_plaintext")
+            .set_payload(payload)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_redirection());
+
+        // 09:00 in Zurich is 07:00 UTC, before the post was published at 08:00:30 UTC
+        let req = test::TestRequest::get().uri("/admin/post/list?filter_published_at_from=2023-07-01T09:00").to_request();
+        let resp = test::call_service(&app, req).await;
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        assert!(body.as_str().contains("/admin/post/show/1"));
+        assert!(body.as_str().contains("value=\"2023-07-01T09:00\""));
+
+        let req = test::TestRequest::get().uri("/admin/post/list?filter_published_at_from=2023-07-01T10:30").to_request();
+        let resp = test::call_service(&app, req).await;
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        assert!(!body.as_str().contains("/admin/post/show/1"));
+
+        let req = test::TestRequest::get().uri("/admin/post/list?filter_published_at_to=2023-07-01T09:00").to_request();
+        let resp = test::call_service(&app, req).await;
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        assert!(!body.as_str().contains("/admin/post/show/1"));
+    }
+
+    #[actix_web::test]
+    async fn timezone_is_stored_in_session() {
+        let db = super::setup_db(false).await;
+        let app = create_app!(db);
+
+        let req = test::TestRequest::post()
+            .uri("/admin/timezone/America/New_York")
+            .insert_header((actix_web::http::header::REFERER, "https://evil.example/admin/post/list"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::SEE_OTHER);
+        assert_eq!(resp.headers().get(actix_web::http::header::LOCATION).unwrap(), "/admin/");
+
+        let req = test::TestRequest::post()
+            .uri("/admin/timezone/America/New_York")
+            .insert_header((actix_web::http::header::REFERER, "/admin/post/list"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.headers().get(actix_web::http::header::LOCATION).unwrap(), "/admin/post/list");
+
+        // the timezone is only changed by a post
+        let req = test::TestRequest::get().uri("/admin/timezone/America/New_York").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert!(!resp.status().is_redirection());
+
+        let req = test::TestRequest::post().uri("/admin/timezone/Mars/Olympus_Mons").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::NOT_FOUND);
+    }
+}